@@ -0,0 +1,86 @@
+//! Compares a bursty input signal with `AnimationMode::Start` when the number of stacked animations
+//! is unlimited, which is the default, against the stack policies. Signals created with
+//! `create_animation()` and `create_animated_signal()` take different paths through the stack
+//! policies, so both are measured.
+
+use std::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use leptos::*;
+use leptos_animation::{
+    clock::ManualClock, create_animated_signal, create_animation, easing, tween_default,
+    AnimatedSignal, AnimationContext, AnimationTarget, StackPolicy,
+};
+
+/// Number of times the input changes within the duration of a single animation
+const UPDATES: usize = 500;
+
+/// Number of stacked animations before the stack policy kicks in
+const STACK_LIMIT: usize = 16;
+
+/// The function that creates the animated signal
+#[derive(Clone, Copy)]
+enum Create {
+    Animation,
+    AnimatedSignal,
+}
+
+fn mouse_move(create: Create, limit: Option<(usize, StackPolicy)>) -> f64 {
+    let runtime = create_runtime();
+    let (context, animation_frame) =
+        AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    let clock = ManualClock::new();
+    context.set_clock(clock.clone());
+
+    let (target, set_target) = create_signal(0.0);
+    let source = move || -> AnimationTarget<f64> {
+        (target.get(), Duration::from_secs(1), easing::LINEAR).into()
+    };
+    let value: AnimatedSignal<f64, f64> = match create {
+        Create::Animation => create_animation(source),
+        Create::AnimatedSignal => create_animated_signal(source, tween_default),
+    };
+    if let Some((max, policy)) = limit {
+        value.set_stack_limit(max, policy);
+    }
+
+    let mut sum = 0.0;
+    for i in 0..UPDATES {
+        set_target.set(i as f64);
+        clock.advance(Duration::from_millis(1));
+        animation_frame();
+        sum += value.get_untracked();
+    }
+
+    runtime.dispose();
+    sum
+}
+
+fn stacked_animations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mouse_move");
+    let creates = [
+        (Create::Animation, "create_animation"),
+        (Create::AnimatedSignal, "create_animated_signal"),
+    ];
+    let limits = [
+        ("unlimited", None),
+        ("merge", Some((STACK_LIMIT, StackPolicy::Merge))),
+        ("drop", Some((STACK_LIMIT, StackPolicy::Drop))),
+        ("replace", Some((STACK_LIMIT, StackPolicy::Replace))),
+    ];
+    for (create, function) in creates {
+        for (name, limit) in limits {
+            // Signals created with create_animated_signal() can't merge animations
+            if let (Create::AnimatedSignal, Some((_, StackPolicy::Merge))) = (create, limit) {
+                continue;
+            }
+            group.bench_with_input(BenchmarkId::new(function, name), &limit, |b, &limit| {
+                b.iter(|| black_box(mouse_move(create, limit)))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, stacked_animations);
+criterion_main!(benches);
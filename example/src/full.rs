@@ -2,7 +2,6 @@ use derive_more::{Add, Mul, Sub};
 use leptos::html::Canvas;
 use leptos::*;
 use leptos_animation::*;
-use palette::{self, convert::FromColorUnclamped, rgb::Rgb, FromColor, Hsv, Mix};
 use std::f64::consts::PI;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::CanvasRenderingContext2d;
@@ -27,21 +26,21 @@ pub fn Full() -> impl IntoView {
 
     // Animated derived signals
     let size = create_animated_signal(
-        move || AnimationTarget {
-            target: target_size.get(),
-            duration: duration.get_untracked().into(),
-            easing: easing.get_untracked().into(),
-            mode: AnimationMode::Start,
+        move || {
+            AnimationTarget::new(target_size.get())
+                .duration(duration.get_untracked().into())
+                .easing(easing.get_untracked().into())
+                .mode(AnimationMode::Start)
         },
         |from, to, progress| tween_default(&from.to_pixels(), &to.to_pixels(), progress),
     );
 
     let rotation = create_animated_signal(
-        move || AnimationTarget {
-            target: target_rotation.get(),
-            duration: duration.get_untracked().into(),
-            easing: easing.get_untracked().into(),
-            mode: AnimationMode::Start,
+        move || {
+            AnimationTarget::new(target_rotation.get())
+                .duration(duration.get_untracked().into())
+                .easing(easing.get_untracked().into())
+                .mode(AnimationMode::Start)
         },
         tween_default,
     );
@@ -49,41 +48,28 @@ pub fn Full() -> impl IntoView {
     let position = create_animated_signal(
         move || {
             let (target, mode) = target_position.get();
-            AnimationTarget {
-                target,
-                duration: duration.get_untracked().into(),
-                easing: match easing.get_untracked() {
+            AnimationTarget::new(target)
+                .duration(duration.get_untracked().into())
+                .easing(match easing.get_untracked() {
                     Easing::Linear => easing::LINEAR,
                     Easing::Smooth => easing::CUBIC_IN_OUT,
                     Easing::Overshoot => easing::BACK_IN_OUT,
                     Easing::Elastic => easing::ELASTIC_IN_OUT,
-                },
-                mode,
-            }
+                })
+                .mode(mode)
         },
         tween_default,
     );
 
     let color = create_animated_signal(
-        move || AnimationTarget {
-            target: target_color.get(),
-            duration: duration.get_untracked().into(),
-            easing: easing.get_untracked().into(),
-            mode: AnimationMode::Start,
-        },
-        |from, to, progress| -> Color {
-            // Convert to HSV to do the tweening
-            let from: Hsv<_, _> = from.into();
-            let to = to.into();
-
-            let mix = Rgb::from_color(from.mix(to, progress));
-
-            Color {
-                red: (mix.red * 255.0) as i16,
-                green: (mix.green * 255.0) as i16,
-                blue: ((mix.blue * 255.0) as i16),
-            }
+        move || {
+            AnimationTarget::new(target_color.get())
+                .duration(duration.get_untracked().into())
+                .easing(easing.get_untracked().into())
+                .mode(AnimationMode::Start)
         },
+        // Tween along the shortest path around the hue circle
+        color::tween_hsv::<Color>,
     );
 
     // Draw a square with the animated signals
@@ -105,8 +91,7 @@ pub fn Full() -> impl IntoView {
             ctx.translate(x, y).unwrap();
             ctx.rotate(rotation.get() / 180.0 * PI).unwrap();
 
-            let Color { red, green, blue } = color.get();
-            ctx.set_fill_style(&JsValue::from_str(&format!("rgb({red}, {green}, {blue})")));
+            ctx.set_fill_style(&JsValue::from_str(&color.get().to_string()));
 
             let size = size.get();
             ctx.fill_rect(-size / 2.0, -size / 2.0, size, size);
@@ -389,20 +374,16 @@ pub fn Full() -> impl IntoView {
     }
 }
 
-#[derive(Clone, Sub)]
+#[derive(Clone)]
 struct Color {
     red: i16,
     green: i16,
     blue: i16,
 }
 
-impl From<&Color> for Hsv<Rgb, f64> {
-    fn from(Color { red, green, blue }: &Color) -> Self {
-        Hsv::from_color_unclamped(Rgb::new(
-            *red as f64 / 255.0,
-            *green as f64 / 255.0,
-            *blue as f64 / 255.0,
-        ))
+impl From<Color> for color::Color {
+    fn from(Color { red, green, blue }: Color) -> Self {
+        color::Color::rgb(red as u8, green as u8, blue as u8)
     }
 }
 
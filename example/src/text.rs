@@ -1,23 +1,12 @@
 use leptos::*;
 use leptos_animation::*;
-use std::ops::Sub;
 
 #[component]
 pub fn Text() -> impl IntoView {
     let (text, set_text) = create_signal("");
 
-    let animated_text = create_animated_signal(
-        move || text.get().into(),
-        |from, to, progress| {
-            // Animate between strings by taking the beginning of the to-string
-            // followed by the end of the from-string
-            TextString(String::from_iter(
-                to.chars()
-                    .take((progress * to.len() as f64) as usize)
-                    .chain(from.chars().skip((progress * from.len() as f64) as usize)),
-            ))
-        },
-    );
+    let animated_text =
+        create_animation::<text::Text>(move || text::Text::typewriter(text.get()).into());
 
     view! {
         <main class="text">
@@ -25,7 +14,7 @@ pub fn Text() -> impl IntoView {
             <button on:click=move |_| { set_text.set("Hello World") }>"Hello"</button>
             <button on:click=move |_| { set_text.set(AUSTIN) }>"Austin"</button>
             <button on:click=move |_| { set_text.set(LOREM_IPSUM) }>"Lorem Ipsum"</button>
-            <textarea prop:value=move || animated_text.get().0></textarea>
+            <textarea prop:value=move || animated_text.get().to_string()></textarea>
             <a
                 class="source"
                 href="https://github.com/PaulWagener/leptos_animation/blob/master/example/src/text.rs"
@@ -36,19 +25,6 @@ pub fn Text() -> impl IntoView {
     }
 }
 
-#[derive(Clone)]
-struct TextString(String);
-
-/// create_animated_signal requires interpolated values to be subtractable to allow for overlapping
-/// animations. This is a very ill-defined operations for strings, so we basically cheat by not providing
-/// a real implementation. This will result in glitches when there are multiple overlapping animations.
-impl Sub for TextString {
-    type Output = TextString;
-    fn sub(self, rhs: Self) -> Self::Output {
-        rhs
-    }
-}
-
 const AUSTIN: &str = "IT is a truth universally acknowledged, that a single man in possession of a good fortune must be in want of a wife.
 However little known the feelings or views of such a man may be on his first entering a neighbourhood, this truth is so well fixed in the minds of the surrounding families, that he is considered as the rightful property of some one or other of their daughters.
 ``My dear Mr. Bennet,'' said his lady to him one day, ``have you heard that Netherfield Park is let at last?''
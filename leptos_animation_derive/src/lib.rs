@@ -0,0 +1,741 @@
+//! Derive macro for the `Animatable` trait of [leptos_animation](https://docs.rs/leptos_animation).
+//!
+//! Enable it with the `derive` feature of `leptos_animation` and use it through the re-export
+//! `leptos_animation::Animate`.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Ident, Path, Result, Type,
+};
+
+/// Derive `Animatable` for a struct or enum, so that it can be used with `create_animation()`.
+///
+/// Structs are interpolated field by field. Every field must be `Animatable` unless one of these
+/// attributes is put on it:
+/// * `#[animate(skip)]` does not animate the field, it immediately takes the target value
+/// * `#[animate(snap)]` switches from the old to the new value halfway through the animation.
+///   The field must implement `PartialEq`.
+/// * `#[animate(tween = path::to::function)]` interpolates the field with a custom tween function
+///   `fn(&T, &T, f64) -> T`. The field must implement `PartialEq`. Because the field can't be
+///   added to, the most recent animation of the field that is still running is shown.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animation, easing, Animate, AnimationContext};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// #[derive(Animate, Clone, Debug, PartialEq)]
+/// struct Card {
+///     x: f64,
+///     y: f64,
+///     #[animate(snap)]
+///     label: &'static str,
+///     #[animate(skip)]
+///     id: u32,
+///     #[animate(tween = round_up)]
+///     opacity: f64,
+/// }
+///
+/// fn round_up(from: &f64, to: &f64, progress: f64) -> f64 {
+///     (from + (to - from) * progress).ceil()
+/// }
+///
+/// let (card, set_card) = create_signal(Card { x: 0.0, y: 0.0, label: "old", id: 1, opacity: 0.0 });
+/// let animated_card =
+///     create_animation::<Card>(move || (card.get(), Duration::from_secs(1), easing::LINEAR).into());
+///
+/// set_card.set(Card { x: 10.0, y: 20.0, label: "new", id: 2, opacity: 1.0 });
+/// clock.advance(Duration::from_millis(250));
+/// animation_frame();
+/// assert_eq!(
+///     animated_card.get_untracked(),
+///     Card { x: 2.5, y: 5.0, label: "old", id: 2, opacity: 1.0 }
+/// );
+/// # runtime.dispose();
+/// ```
+///
+/// Enums are interpolated field by field when the animation is between two values of the same
+/// variant. There are no values in between two different variants, so by default those switch
+/// halfway through the animation. The variant fields take the same attributes as struct fields.
+///
+/// With `#[animate(snap)]` on the enum itself the fields are never interpolated and the enum always
+/// switches halfway through the animation. The enum must implement `PartialEq`.
+///
+/// With `#[animate(crossfade = path::to::function)]` on the enum itself an animation between two
+/// different variants calls the function `fn(&Self, &Self, f64) -> Self` instead of switching
+/// halfway. It returns the value in between, for example a variant that shows both values on
+/// top of each other. The animation starts at the old value and ends at the new value, the
+/// function is only called for the progress in between.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animation, easing, Animate, AnimationContext};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// #[derive(Animate, Clone, Debug, PartialEq)]
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Square(f64),
+/// }
+///
+/// let (shape, set_shape) = create_signal(Shape::Circle { radius: 0.0 });
+/// let animated_shape =
+///     create_animation::<Shape>(move || (shape.get(), Duration::from_secs(1), easing::LINEAR).into());
+///
+/// set_shape.set(Shape::Circle { radius: 10.0 });
+/// clock.advance(Duration::from_millis(500));
+/// animation_frame();
+/// assert_eq!(animated_shape.get_untracked(), Shape::Circle { radius: 5.0 });
+///
+/// set_shape.set(Shape::Square(10.0));
+/// clock.advance(Duration::from_millis(500));
+/// animation_frame();
+/// assert_eq!(animated_shape.get_untracked(), Shape::Square(10.0));
+/// # runtime.dispose();
+/// ```
+///
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animation, easing, Animate, AnimationContext};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// #[derive(Animate, Clone, Debug, PartialEq)]
+/// #[animate(crossfade = crossfade)]
+/// enum Slide {
+///     Image(#[animate(snap)] &'static str),
+///     Caption(#[animate(snap)] &'static str),
+///     /// `to` is shown on top of `from` with the given opacity
+///     Crossfade {
+///         #[animate(skip)]
+///         from: Box<Slide>,
+///         #[animate(skip)]
+///         to: Box<Slide>,
+///         opacity: f64,
+///     },
+/// }
+///
+/// fn crossfade(from: &Slide, to: &Slide, progress: f64) -> Slide {
+///     let from = Box::new(from.clone());
+///     let to = Box::new(to.clone());
+///     Slide::Crossfade { from, to, opacity: progress }
+/// }
+///
+/// let (slide, set_slide) = create_signal(Slide::Image("cat.png"));
+/// let animated_slide =
+///     create_animation::<Slide>(move || (slide.get(), Duration::from_secs(1), easing::LINEAR).into());
+///
+/// set_slide.set(Slide::Caption("A cat"));
+/// clock.advance(Duration::from_millis(250));
+/// animation_frame();
+/// assert_eq!(
+///     animated_slide.get_untracked(),
+///     Slide::Crossfade {
+///         from: Box::new(Slide::Image("cat.png")),
+///         to: Box::new(Slide::Caption("A cat")),
+///         opacity: 0.25,
+///     }
+/// );
+///
+/// clock.advance(Duration::from_millis(750));
+/// animation_frame();
+/// assert_eq!(animated_slide.get_untracked(), Slide::Caption("A cat"));
+/// # runtime.dispose();
+/// ```
+#[proc_macro_derive(Animate, attributes(animate))]
+pub fn derive_animate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// How a single field is animated
+enum FieldKind {
+    Animate,
+    Skip,
+    Snap,
+    Tween(Path),
+}
+
+/// Attributes on the type itself
+#[derive(Default)]
+struct Container {
+    /// Switch the whole value halfway instead of interpolating its fields
+    snap: bool,
+
+    /// The function that animates between two different variants of an enum
+    crossfade: Option<Path>,
+}
+
+struct Field {
+    /// The name of a named field, `None` for tuple fields
+    name: Option<Ident>,
+    ty: Type,
+    kind: FieldKind,
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let Container { snap, crossfade } = parse_container_attributes(&input)?;
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            if let Some(crossfade) = &crossfade {
+                return Err(Error::new_spanned(
+                    crossfade,
+                    "`crossfade` can only be used on enums",
+                ));
+            }
+            if snap {
+                expand_snap()
+            } else {
+                let fields = parse_fields(&data.fields)?;
+                add_bounds(&mut generics, &fields);
+                expand_struct(&data.fields, &fields)
+            }
+        }
+        Data::Enum(data) => {
+            if snap {
+                expand_snap()
+            } else {
+                let mut variants = vec![];
+                for variant in &data.variants {
+                    let fields = parse_fields(&variant.fields)?;
+                    add_bounds(&mut generics, &fields);
+                    variants.push((&variant.ident, &variant.fields, fields));
+                }
+                expand_enum(&variants, crossfade.as_ref())
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Animate can't be derived for unions",
+            ))
+        }
+    };
+
+    if snap {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(Self: ::core::cmp::PartialEq));
+    }
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #[allow(unused_variables, unreachable_patterns)]
+        impl #impl_generics ::leptos_animation::Animatable for #name #type_generics #where_clause {
+            #body
+        }
+    })
+}
+
+fn parse_container_attributes(input: &DeriveInput) -> Result<Container> {
+    let mut container = Container::default();
+    for attr in &input.attrs {
+        if attr.path().is_ident("animate") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("snap") {
+                    container.snap = true;
+                } else if meta.path.is_ident("crossfade") {
+                    container.crossfade = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected `snap` or `crossfade = ...`"));
+                }
+                Ok(())
+            })?;
+        }
+    }
+    if let (true, Some(crossfade)) = (container.snap, &container.crossfade) {
+        return Err(Error::new_spanned(
+            crossfade,
+            "`crossfade` can't be combined with `snap`",
+        ));
+    }
+    Ok(container)
+}
+
+fn parse_fields(fields: &Fields) -> Result<Vec<Field>> {
+    fields
+        .iter()
+        .map(|field| {
+            let mut kind = FieldKind::Animate;
+            for attr in &field.attrs {
+                if attr.path().is_ident("animate") {
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("skip") {
+                            kind = FieldKind::Skip;
+                        } else if meta.path.is_ident("snap") {
+                            kind = FieldKind::Snap;
+                        } else if meta.path.is_ident("tween") {
+                            kind = FieldKind::Tween(meta.value()?.parse()?);
+                        } else {
+                            return Err(meta.error("expected `skip`, `snap` or `tween = ...`"));
+                        }
+                        Ok(())
+                    })?;
+                }
+            }
+            Ok(Field {
+                name: field.ident.clone(),
+                ty: field.ty.clone(),
+                kind,
+            })
+        })
+        .collect()
+}
+
+/// Fields of generic types need bounds on the generic parameters
+fn add_bounds(generics: &mut syn::Generics, fields: &[Field]) {
+    if generics.type_params().next().is_none() {
+        return;
+    }
+    let where_clause = generics.make_where_clause();
+    for field in fields {
+        let ty = &field.ty;
+        match field.kind {
+            FieldKind::Animate => where_clause
+                .predicates
+                .push(parse_quote!(#ty: ::leptos_animation::Animatable)),
+            FieldKind::Skip => {}
+            FieldKind::Snap | FieldKind::Tween(_) => where_clause
+                .predicates
+                .push(parse_quote!(#ty: ::core::clone::Clone + ::core::cmp::PartialEq)),
+        }
+    }
+}
+
+/// Animate the whole value by switching halfway
+fn expand_snap() -> TokenStream {
+    quote! {
+        type Delta = ::core::option::Option<Self>;
+
+        fn interpolate(&self, to: &Self, progress: f64) -> Self {
+            if progress < 0.5 {
+                ::core::clone::Clone::clone(self)
+            } else {
+                ::core::clone::Clone::clone(to)
+            }
+        }
+
+        fn delta(&self, other: &Self) -> Self::Delta {
+            if self == other {
+                ::core::option::Option::None
+            } else {
+                ::core::option::Option::Some(::core::clone::Clone::clone(self))
+            }
+        }
+
+        fn add_delta(&self, delta: &Self::Delta) -> Self {
+            match delta {
+                ::core::option::Option::Some(value) => ::core::clone::Clone::clone(value),
+                ::core::option::Option::None => ::core::clone::Clone::clone(self),
+            }
+        }
+
+        fn zero_delta() -> Self::Delta {
+            ::core::option::Option::None
+        }
+    }
+}
+
+fn expand_struct(shape: &Fields, fields: &[Field]) -> TokenStream {
+    let path = quote!(Self);
+    let delta_type = delta_type(fields);
+    let from = pattern(&path, shape, fields, "from");
+    let to = pattern(&path, shape, fields, "to");
+    let other = pattern(&path, shape, fields, "other");
+    let deltas = bindings(fields, "delta");
+    let interpolate = construct(&path, shape, fields, interpolate_fields(fields));
+    let delta = delta_fields(fields);
+    let add_delta = construct(&path, shape, fields, add_delta_fields(fields));
+    let zero_delta = zero_delta_fields(fields);
+    let add_deltas = construct(&path, shape, fields, add_deltas_fields(fields));
+    let interpolate_delta = interpolate_delta_fields(fields);
+
+    quote! {
+        type Delta = #delta_type;
+
+        fn interpolate(&self, to: &Self, progress: f64) -> Self {
+            let #from = self;
+            let #to = to;
+            #interpolate
+        }
+
+        fn delta(&self, other: &Self) -> Self::Delta {
+            let #from = self;
+            let #other = other;
+            (#(#delta,)*)
+        }
+
+        fn add_delta(&self, delta: &Self::Delta) -> Self {
+            let #from = self;
+            let (#(#deltas,)*) = delta;
+            #add_delta
+        }
+
+        fn zero_delta() -> Self::Delta {
+            (#(#zero_delta,)*)
+        }
+
+        fn interpolate_delta(&self, to: &Self, progress: f64, other: &Self) -> Self::Delta {
+            let #from = self;
+            let #to = to;
+            let #other = other;
+            (#(#interpolate_delta,)*)
+        }
+
+        fn add_deltas(&self, __deltas: &[&Self::Delta]) -> Self {
+            let #from = self;
+            #add_deltas
+        }
+    }
+}
+
+fn expand_enum(
+    variants: &[(&Ident, &Fields, Vec<Field>)],
+    crossfade: Option<&Path>,
+) -> TokenStream {
+    let count = variants.len();
+    let delta_types = variants.iter().map(|(_, _, fields)| delta_type(fields));
+
+    let mut interpolate_arms = vec![];
+    let mut delta_arms = vec![];
+    let mut add_delta_arms = vec![];
+    let mut add_deltas_arms = vec![];
+    let mut interpolate_delta_arms = vec![];
+    for (index, (name, shape, fields)) in variants.iter().enumerate() {
+        let path = quote!(Self::#name);
+        let from = pattern(&path, shape, fields, "from");
+        let to = pattern(&path, shape, fields, "to");
+        let other = pattern(&path, shape, fields, "other");
+        let deltas = bindings(fields, "delta");
+
+        let interpolate = construct(&path, shape, fields, interpolate_fields(fields));
+        interpolate_arms.push(quote!((#from, #to) => #interpolate));
+
+        let delta = delta_fields(fields);
+        let entries = (0..count).map(|i| {
+            if i == index {
+                quote!(::core::option::Option::Some((#(#delta,)*)))
+            } else {
+                quote!(::core::option::Option::None)
+            }
+        });
+        delta_arms.push(quote! {
+            (#from, #other) => ::leptos_animation::animatable::VariantDelta::Fields((#(#entries,)*))
+        });
+
+        let interpolate_delta = interpolate_delta_fields(fields);
+        let entries = (0..count).map(|i| {
+            if i == index {
+                quote!(::core::option::Option::Some((#(#interpolate_delta,)*)))
+            } else {
+                quote!(::core::option::Option::None)
+            }
+        });
+        interpolate_delta_arms.push(quote! {
+            (#from, #to, #other) => ::leptos_animation::animatable::VariantDelta::Fields((#(#entries,)*))
+        });
+
+        let add_delta = construct(&path, shape, fields, add_delta_fields(fields));
+        let entries = (0..count).map(|i| {
+            if i == index {
+                quote!(::core::option::Option::Some((#(#deltas,)*)))
+            } else {
+                quote!(_)
+            }
+        });
+        add_delta_arms.push(quote! {
+            (#from, ::leptos_animation::animatable::VariantDelta::Fields((#(#entries,)*))) => #add_delta
+        });
+
+        let add_deltas = construct(&path, shape, fields, add_deltas_fields(fields));
+        let index = syn::Index::from(index);
+        add_deltas_arms.push(quote! {
+            #from => {
+                let __deltas: ::std::vec::Vec<_> = ::core::iter::Iterator::collect(
+                    ::core::iter::Iterator::filter_map(__fields.iter(), |__entries| {
+                        ::core::option::Option::as_ref(&__entries.#index)
+                    }),
+                );
+                #add_deltas
+            }
+        });
+    }
+    let nones = (0..count).map(|_| quote!(::core::option::Option::None));
+    let change_variant = match crossfade {
+        Some(crossfade) => quote! {
+            _ if progress <= 0.0 => ::core::clone::Clone::clone(self),
+            _ if progress >= 1.0 => ::core::clone::Clone::clone(to),
+            _ => #crossfade(self, to, progress),
+        },
+        None => quote! {
+            _ if progress < 0.5 => ::core::clone::Clone::clone(self),
+            _ => ::core::clone::Clone::clone(to),
+        },
+    };
+
+    quote! {
+        type Delta = ::leptos_animation::animatable::VariantDelta<
+            Self,
+            (#(::core::option::Option<#delta_types>,)*)
+        >;
+
+        fn interpolate(&self, to: &Self, progress: f64) -> Self {
+            match (self, to) {
+                #(#interpolate_arms,)*
+                #change_variant
+            }
+        }
+
+        fn delta(&self, other: &Self) -> Self::Delta {
+            match (self, other) {
+                #(#delta_arms,)*
+                _ => ::leptos_animation::animatable::VariantDelta::Replace(
+                    ::core::clone::Clone::clone(self)
+                ),
+            }
+        }
+
+        fn add_delta(&self, delta: &Self::Delta) -> Self {
+            match (self, delta) {
+                (_, ::leptos_animation::animatable::VariantDelta::Replace(value)) => {
+                    ::core::clone::Clone::clone(value)
+                }
+                #(#add_delta_arms,)*
+                _ => ::core::clone::Clone::clone(self),
+            }
+        }
+
+        fn zero_delta() -> Self::Delta {
+            ::leptos_animation::animatable::VariantDelta::Fields((#(#nones,)*))
+        }
+
+        fn interpolate_delta(&self, to: &Self, progress: f64, other: &Self) -> Self::Delta {
+            match (self, to, other) {
+                #(#interpolate_delta_arms,)*
+                _ => ::leptos_animation::Animatable::delta(
+                    &::leptos_animation::Animatable::interpolate(self, to, progress),
+                    other,
+                ),
+            }
+        }
+
+        fn add_deltas(&self, __deltas: &[&Self::Delta]) -> Self {
+            // Differences before the last replacement have no effect
+            let mut __value = self;
+            let mut __fields = ::std::vec::Vec::new();
+            for __delta in __deltas {
+                match __delta {
+                    ::leptos_animation::animatable::VariantDelta::Replace(__replacement) => {
+                        __value = __replacement;
+                        __fields.clear();
+                    }
+                    ::leptos_animation::animatable::VariantDelta::Fields(__entries) => {
+                        __fields.push(__entries);
+                    }
+                }
+            }
+            match __value {
+                #(#add_deltas_arms,)*
+                _ => ::core::clone::Clone::clone(__value),
+            }
+        }
+    }
+}
+
+/// Variable names for the fields of one value, such as `__from_0`
+fn bindings(fields: &[Field], prefix: &str) -> Vec<Ident> {
+    (0..fields.len())
+        .map(|i| format_ident!("__{}_{}", prefix, i))
+        .collect()
+}
+
+/// A pattern that binds all fields of a value to variables
+fn pattern(path: &TokenStream, shape: &Fields, fields: &[Field], prefix: &str) -> TokenStream {
+    construct(path, shape, fields, bindings(fields, prefix))
+}
+
+/// An expression or pattern with the given value for each field
+fn construct(
+    path: &TokenStream,
+    shape: &Fields,
+    fields: &[Field],
+    values: impl IntoIterator<Item = impl quote::ToTokens>,
+) -> TokenStream {
+    let values = values.into_iter();
+    match shape {
+        Fields::Named(_) => {
+            let names = fields.iter().map(|field| &field.name);
+            quote!(#path { #(#names: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+        Fields::Unit => quote!(#path),
+    }
+}
+
+fn delta_type(fields: &[Field]) -> TokenStream {
+    let types = fields.iter().map(|field| {
+        let ty = &field.ty;
+        match field.kind {
+            FieldKind::Animate => quote!(<#ty as ::leptos_animation::Animatable>::Delta),
+            FieldKind::Skip => quote!(()),
+            FieldKind::Snap | FieldKind::Tween(_) => quote!(::core::option::Option<#ty>),
+        }
+    });
+    quote!((#(#types,)*))
+}
+
+fn interpolate_fields(fields: &[Field]) -> Vec<TokenStream> {
+    let from = bindings(fields, "from");
+    let to = bindings(fields, "to");
+    fields
+        .iter()
+        .zip(from.iter().zip(&to))
+        .map(|(field, (from, to))| match &field.kind {
+            FieldKind::Animate => {
+                quote!(::leptos_animation::Animatable::interpolate(#from, #to, progress))
+            }
+            FieldKind::Skip => quote!(::core::clone::Clone::clone(#to)),
+            FieldKind::Snap => quote! {
+                if progress < 0.5 {
+                    ::core::clone::Clone::clone(#from)
+                } else {
+                    ::core::clone::Clone::clone(#to)
+                }
+            },
+            FieldKind::Tween(tween) => quote!(#tween(#from, #to, progress)),
+        })
+        .collect()
+}
+
+fn delta_fields(fields: &[Field]) -> Vec<TokenStream> {
+    let from = bindings(fields, "from");
+    let other = bindings(fields, "other");
+    fields
+        .iter()
+        .zip(from.iter().zip(&other))
+        .map(|(field, (from, other))| match field.kind {
+            FieldKind::Animate => quote!(::leptos_animation::Animatable::delta(#from, #other)),
+            FieldKind::Skip => quote!(()),
+            FieldKind::Snap | FieldKind::Tween(_) => quote! {
+                if #from == #other {
+                    ::core::option::Option::None
+                } else {
+                    ::core::option::Option::Some(::core::clone::Clone::clone(#from))
+                }
+            },
+        })
+        .collect()
+}
+
+fn add_delta_fields(fields: &[Field]) -> Vec<TokenStream> {
+    let from = bindings(fields, "from");
+    let delta = bindings(fields, "delta");
+    fields
+        .iter()
+        .zip(from.iter().zip(&delta))
+        .map(|(field, (from, delta))| match field.kind {
+            FieldKind::Animate => quote!(::leptos_animation::Animatable::add_delta(#from, #delta)),
+            FieldKind::Skip => quote!(::core::clone::Clone::clone(#from)),
+            FieldKind::Snap | FieldKind::Tween(_) => quote! {
+                match #delta {
+                    ::core::option::Option::Some(value) => ::core::clone::Clone::clone(value),
+                    ::core::option::Option::None => ::core::clone::Clone::clone(#from),
+                }
+            },
+        })
+        .collect()
+}
+
+/// The difference between the interpolated fields and the fields of `other`
+fn interpolate_delta_fields(fields: &[Field]) -> Vec<TokenStream> {
+    let from = bindings(fields, "from");
+    let to = bindings(fields, "to");
+    let other = bindings(fields, "other");
+    fields
+        .iter()
+        .zip(interpolate_fields(fields))
+        .enumerate()
+        .map(|(index, (field, interpolate))| {
+            let (from, to, other) = (&from[index], &to[index], &other[index]);
+            match field.kind {
+                FieldKind::Animate => quote! {
+                    ::leptos_animation::Animatable::interpolate_delta(#from, #to, progress, #other)
+                },
+                FieldKind::Skip => quote!(()),
+                FieldKind::Snap | FieldKind::Tween(_) => quote! {{
+                    let __value = #interpolate;
+                    if &__value == #other {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(__value)
+                    }
+                }},
+            }
+        })
+        .collect()
+}
+
+/// Add the differences in `__deltas` to each field at once
+fn add_deltas_fields(fields: &[Field]) -> Vec<TokenStream> {
+    let from = bindings(fields, "from");
+    fields
+        .iter()
+        .zip(&from)
+        .enumerate()
+        .map(|(index, (field, from))| {
+            let index = syn::Index::from(index);
+            let deltas = quote! {
+                ::core::iter::Iterator::map(__deltas.iter(), |__delta| &__delta.#index)
+            };
+            match field.kind {
+                FieldKind::Animate => quote! {
+                    ::leptos_animation::Animatable::add_deltas(
+                        #from,
+                        &::core::iter::Iterator::collect::<::std::vec::Vec<_>>(#deltas),
+                    )
+                },
+                FieldKind::Skip => quote!(::core::clone::Clone::clone(#from)),
+                FieldKind::Snap | FieldKind::Tween(_) => quote! {
+                    match ::core::iter::Iterator::last(::core::iter::Iterator::filter_map(
+                        #deltas,
+                        ::core::option::Option::as_ref,
+                    )) {
+                        ::core::option::Option::Some(value) => ::core::clone::Clone::clone(value),
+                        ::core::option::Option::None => ::core::clone::Clone::clone(#from),
+                    }
+                },
+            }
+        })
+        .collect()
+}
+
+fn zero_delta_fields(fields: &[Field]) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+            match field.kind {
+                FieldKind::Animate => {
+                    quote!(<#ty as ::leptos_animation::Animatable>::zero_delta())
+                }
+                FieldKind::Skip => quote!(()),
+                FieldKind::Snap | FieldKind::Tween(_) => quote!(::core::option::Option::None),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,294 @@
+//! The [`Animatable`] trait and its implementations for common types:
+//! * Floats are interpolated linearly.
+//! * Integers are interpolated linearly and rounded to the nearest integer. Their difference is an `f64`.
+//!   The differences of overlapping animations are added up before rounding.
+//! * Tuples and arrays are interpolated element by element.
+//! * `Option`s are interpolated when both are `Some`, otherwise they switch halfway through the animation.
+//!
+//! Because the differences of integers are added up before rounding, an integer that is sent back
+//! to where it came from halfway through an animation continues from where it is, even though the
+//! animations that make up its value go outside of the range of the type:
+//! ```
+//! # use std::time::Duration;
+//! # use leptos::*;
+//! # use leptos_animation::{clock::ManualClock, create_animation, easing, AnimationContext};
+//! # let runtime = create_runtime();
+//! # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+//! # let clock = ManualClock::new();
+//! # context.set_clock(clock.clone());
+//! let (level, set_level) = create_signal(0_u8);
+//! let animated_level =
+//!     create_animation::<u8>(move || (level.get(), Duration::from_secs(1), easing::LINEAR).into());
+//!
+//! set_level.set(255);
+//! clock.advance(Duration::from_millis(500));
+//! animation_frame();
+//! assert_eq!(animated_level.get_untracked(), 128);
+//!
+//! set_level.set(0);
+//! animation_frame();
+//! assert_eq!(animated_level.get_untracked(), 128);
+//!
+//! clock.advance(Duration::from_millis(500));
+//! animation_frame();
+//! assert_eq!(animated_level.get_untracked(), 128);
+//!
+//! clock.advance(Duration::from_millis(500));
+//! animation_frame();
+//! assert_eq!(animated_level.get_untracked(), 0);
+//! # runtime.dispose();
+//! ```
+
+/// A type that can be animated without a separate tween function, see
+/// [`create_animation()`](crate::create_animation).
+///
+/// Animations are additive, which requires the difference between two values to be added to
+/// another value. The difference is a separate [`Delta`](Animatable::Delta) type so that values
+/// such as unsigned integers can have negative differences.
+///
+/// This trait is implemented for all primitive numbers, tuples, arrays and `Option`s of animatable
+/// values, see the [module documentation](crate::animatable) for details. With the `derive` feature
+/// it can be derived for structs and enums with `#[derive(Animate)]`.
+pub trait Animatable: Clone {
+    /// The difference between two values
+    type Delta;
+
+    /// Linearly interpolate between `self` at progress 0.0 and `to` at progress 1.0
+    fn interpolate(&self, to: &Self, progress: f64) -> Self;
+
+    /// The difference `self - other`
+    fn delta(&self, other: &Self) -> Self::Delta;
+
+    /// Add a difference to this value
+    fn add_delta(&self, delta: &Self::Delta) -> Self;
+
+    /// The difference that leaves a value unchanged when it is added
+    fn zero_delta() -> Self::Delta;
+
+    /// The difference between `self.interpolate(to, progress)` and `other`. The progress may lie
+    /// outside of 0.0 to 1.0 when an animation overshoots or keeps its velocity after retargeting.
+    /// Types that round or clamp in [`interpolate()`](Animatable::interpolate) compute the
+    /// difference directly, so that it is not cut off at the bounds of the type.
+    fn interpolate_delta(&self, to: &Self, progress: f64, other: &Self) -> Self::Delta {
+        self.interpolate(to, progress).delta(other)
+    }
+
+    /// Add several differences to this value, the oldest first. By default they are added one by
+    /// one. Types that round or clamp in [`add_delta()`](Animatable::add_delta) combine the
+    /// differences first, so that overlapping animations only round once.
+    fn add_deltas(&self, deltas: &[&Self::Delta]) -> Self {
+        deltas
+            .iter()
+            .fold(self.clone(), |value, delta| value.add_delta(delta))
+    }
+}
+
+macro_rules! impl_animatable_float {
+    ($($float:ty),*) => {$(
+        impl Animatable for $float {
+            type Delta = $float;
+
+            fn interpolate(&self, to: &Self, progress: f64) -> Self {
+                self + (to - self) * progress as $float
+            }
+
+            fn delta(&self, other: &Self) -> Self::Delta {
+                self - other
+            }
+
+            fn add_delta(&self, delta: &Self::Delta) -> Self {
+                self + delta
+            }
+
+            fn zero_delta() -> Self::Delta {
+                0.0
+            }
+        }
+    )*};
+}
+
+impl_animatable_float!(f32, f64);
+
+macro_rules! impl_animatable_integer {
+    ($($integer:ty),*) => {$(
+        impl Animatable for $integer {
+            type Delta = f64;
+
+            fn interpolate(&self, to: &Self, progress: f64) -> Self {
+                (*self as f64 + (*to as f64 - *self as f64) * progress).round() as $integer
+            }
+
+            fn delta(&self, other: &Self) -> Self::Delta {
+                *self as f64 - *other as f64
+            }
+
+            fn add_delta(&self, delta: &Self::Delta) -> Self {
+                (*self as f64 + delta).round() as $integer
+            }
+
+            fn zero_delta() -> Self::Delta {
+                0.0
+            }
+
+            fn interpolate_delta(&self, to: &Self, progress: f64, other: &Self) -> Self::Delta {
+                *self as f64 + (*to as f64 - *self as f64) * progress - *other as f64
+            }
+
+            fn add_deltas(&self, deltas: &[&Self::Delta]) -> Self {
+                (*self as f64 + deltas.iter().copied().sum::<f64>()).round() as $integer
+            }
+        }
+    )*};
+}
+
+impl_animatable_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_animatable_tuple {
+    ($($name:ident : $index:tt),*) => {
+        impl<$($name: Animatable),*> Animatable for ($($name,)*) {
+            type Delta = ($($name::Delta,)*);
+
+            fn interpolate(&self, to: &Self, progress: f64) -> Self {
+                ($(self.$index.interpolate(&to.$index, progress),)*)
+            }
+
+            fn delta(&self, other: &Self) -> Self::Delta {
+                ($(self.$index.delta(&other.$index),)*)
+            }
+
+            fn add_delta(&self, delta: &Self::Delta) -> Self {
+                ($(self.$index.add_delta(&delta.$index),)*)
+            }
+
+            fn zero_delta() -> Self::Delta {
+                ($($name::zero_delta(),)*)
+            }
+
+            fn interpolate_delta(&self, to: &Self, progress: f64, other: &Self) -> Self::Delta {
+                ($(self.$index.interpolate_delta(&to.$index, progress, &other.$index),)*)
+            }
+
+            fn add_deltas(&self, deltas: &[&Self::Delta]) -> Self {
+                ($(self.$index.add_deltas(
+                    &deltas.iter().map(|delta| &delta.$index).collect::<Vec<_>>()
+                ),)*)
+            }
+        }
+    };
+}
+
+impl_animatable_tuple!(A: 0);
+impl_animatable_tuple!(A: 0, B: 1);
+impl_animatable_tuple!(A: 0, B: 1, C: 2);
+impl_animatable_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_animatable_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_animatable_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_animatable_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_animatable_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
+impl<T: Animatable, const N: usize> Animatable for [T; N] {
+    type Delta = [T::Delta; N];
+
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        std::array::from_fn(|i| self[i].interpolate(&to[i], progress))
+    }
+
+    fn delta(&self, other: &Self) -> Self::Delta {
+        std::array::from_fn(|i| self[i].delta(&other[i]))
+    }
+
+    fn add_delta(&self, delta: &Self::Delta) -> Self {
+        std::array::from_fn(|i| self[i].add_delta(&delta[i]))
+    }
+
+    fn zero_delta() -> Self::Delta {
+        std::array::from_fn(|_| T::zero_delta())
+    }
+
+    fn interpolate_delta(&self, to: &Self, progress: f64, other: &Self) -> Self::Delta {
+        std::array::from_fn(|i| self[i].interpolate_delta(&to[i], progress, &other[i]))
+    }
+
+    fn add_deltas(&self, deltas: &[&Self::Delta]) -> Self {
+        std::array::from_fn(|i| {
+            self[i].add_deltas(&deltas.iter().map(|delta| &delta[i]).collect::<Vec<_>>())
+        })
+    }
+}
+
+/// The difference between two `Option` values
+pub enum OptionDelta<T: Animatable> {
+    /// Both values are `Some`, the difference between them can be added
+    Some(T::Delta),
+
+    /// One of the values is `None`, adding this difference replaces the value
+    Replace(Option<T>),
+}
+
+impl<T: Animatable> Animatable for Option<T> {
+    type Delta = OptionDelta<T>;
+
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        match (self, to) {
+            (Some(from), Some(to)) => Some(from.interpolate(to, progress)),
+            _ if progress < 0.5 => self.clone(),
+            _ => to.clone(),
+        }
+    }
+
+    fn delta(&self, other: &Self) -> Self::Delta {
+        match (self, other) {
+            (Some(value), Some(other)) => OptionDelta::Some(value.delta(other)),
+            _ => OptionDelta::Replace(self.clone()),
+        }
+    }
+
+    fn add_delta(&self, delta: &Self::Delta) -> Self {
+        match (self, delta) {
+            (Some(value), OptionDelta::Some(delta)) => Some(value.add_delta(delta)),
+            (None, OptionDelta::Some(_)) => None,
+            (_, OptionDelta::Replace(value)) => value.clone(),
+        }
+    }
+
+    fn zero_delta() -> Self::Delta {
+        OptionDelta::Some(T::zero_delta())
+    }
+
+    fn interpolate_delta(&self, to: &Self, progress: f64, other: &Self) -> Self::Delta {
+        match (self, to, other) {
+            (Some(from), Some(to), Some(other)) => {
+                OptionDelta::Some(from.interpolate_delta(to, progress, other))
+            }
+            _ => self.interpolate(to, progress).delta(other),
+        }
+    }
+
+    fn add_deltas(&self, deltas: &[&Self::Delta]) -> Self {
+        // Differences before the last replacement have no effect
+        let mut value = self;
+        let mut added = Vec::new();
+        for delta in deltas {
+            match delta {
+                OptionDelta::Some(delta) => added.push(delta),
+                OptionDelta::Replace(replacement) => {
+                    value = replacement;
+                    added.clear();
+                }
+            }
+        }
+        value.as_ref().map(|value| value.add_deltas(&added))
+    }
+}
+
+/// The difference between two values of an enum that derives `Animate`. When
+/// both values are the same variant the difference is the difference between their fields,
+/// otherwise adding the difference replaces the value.
+pub enum VariantDelta<T, D> {
+    /// The difference between the fields, the tuple has an entry for each variant of which only
+    /// the entry of the shared variant is `Some`
+    Fields(D),
+
+    /// The values are different variants, adding this difference replaces the value
+    Replace(T),
+}
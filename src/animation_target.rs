@@ -1,108 +1,302 @@
 use std::time::Duration;
 
-use crate::{easing::SINE_OUT, AnimationMode, AnimationTarget, Easing};
+use leptos::{provide_context, use_context, Owner};
 
-const DEFAULT_MODE: AnimationMode = AnimationMode::Start;
-const DEFAULT_DURATION: Duration = Duration::new(0, 500 * 1000 * 1000);
-const DEFAULT_EASING: Easing = SINE_OUT;
+use crate::{
+    easing::{LINEAR, SINE_OUT},
+    keyframes::{valid_keyframes, Keyframe},
+    spring::Spring,
+    AnimationDirection, AnimationMode, AnimationTarget, Easing, MotionPolicy, Repeat,
+    REDUCED_MOTION_DURATION,
+};
 
-impl<T: Default> Default for AnimationTarget<T> {
+/// The options that animations get when they are not set on the [`AnimationTarget`], for example
+/// when calling `.into()` on a plain target value.
+///
+/// Defaults can be provided for the whole application with [`AnimationContext::provide_with_defaults()`](crate::AnimationContext::provide_with_defaults)
+/// and overridden for a part of the application by calling [`provide()`](AnimationDefaults::provide) in a child component.
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{easing, AnimationContext, AnimationDefaults, AnimationTarget};
+/// # let runtime = create_runtime();
+/// AnimationContext::provide_with_defaults(AnimationDefaults {
+///     duration: Duration::from_secs(1),
+///     ..Default::default()
+/// });
+/// assert_eq!(AnimationTarget::from(42.0).duration, Duration::from_secs(1));
+///
+/// // A section of the application with faster animations
+/// run_as_child(|| {
+///     AnimationDefaults {
+///         duration: Duration::from_millis(150),
+///         easing: easing::LINEAR.into(),
+///         ..AnimationDefaults::current()
+///     }
+///     .provide();
+///     assert_eq!(AnimationTarget::from(42.0).duration, Duration::from_millis(150));
+/// });
+///
+/// assert_eq!(AnimationTarget::from(42.0).duration, Duration::from_secs(1));
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationDefaults {
+    /// Defaults to 0.5 seconds
+    pub duration: Duration,
+
+    /// Defaults to [`SINE_OUT`]
+    pub easing: Easing,
+
+    /// Animate with a spring instead of a duration and easing. Defaults to `None`
+    pub spring: Option<Spring>,
+
+    /// Defaults to [`AnimationMode::Start`]
+    pub mode: AnimationMode,
+
+    /// Defaults to no delay
+    pub delay: Duration,
+
+    /// Defaults to playing once
+    pub repeat: Repeat,
+
+    /// Defaults to [`AnimationDirection::Normal`]
+    pub direction: AnimationDirection,
+}
+
+impl Default for AnimationDefaults {
     fn default() -> Self {
-        AnimationTarget {
-            target: T::default(),
-            mode: DEFAULT_MODE,
-            duration: DEFAULT_DURATION,
-            easing: DEFAULT_EASING
+        AnimationDefaults {
+            duration: Duration::from_millis(500),
+            easing: SINE_OUT.into(),
+            spring: None,
+            mode: AnimationMode::Start,
+            delay: Duration::ZERO,
+            repeat: Repeat::Count(1.0),
+            direction: AnimationDirection::Normal,
         }
     }
 }
 
-impl<T> From<T> for AnimationTarget<T> {
-    fn from(target: T) -> Self {
+impl AnimationDefaults {
+    /// Use these defaults for animation targets created in this scope and all child scopes
+    pub fn provide(self) {
+        provide_context(self);
+    }
+
+    /// The defaults for the current scope. These are the built-in defaults when none are provided
+    /// or when called outside of a reactive scope.
+    pub fn current() -> AnimationDefaults {
+        Owner::current()
+            .and_then(|_| use_context())
+            .unwrap_or_default()
+    }
+}
+
+impl<T> AnimationTarget<T> {
+    /// Animate towards `target` with the [`AnimationDefaults`] of the current scope
+    /// ```
+    /// # use leptos_animation::AnimationTarget;
+    /// assert_eq!(AnimationTarget::new(42), 42.into());
+    /// ```
+    pub fn new(target: T) -> Self {
+        let defaults = AnimationDefaults::current();
         AnimationTarget {
             target,
-            mode: DEFAULT_MODE,
-            duration: DEFAULT_DURATION,
-            easing: DEFAULT_EASING,
+            mode: defaults.mode,
+            duration: defaults.duration,
+            easing: defaults.easing,
+            spring: defaults.spring,
+            keyframes: vec![],
+            delay: defaults.delay,
+            repeat: defaults.repeat,
+            direction: defaults.direction,
+        }
+    }
+
+    /// Play the animation for the given time
+    pub fn duration(self, duration: Duration) -> Self {
+        AnimationTarget { duration, ..self }
+    }
+
+    /// Apply the given easing during the animation
+    pub fn easing(self, easing: impl Into<Easing>) -> Self {
+        AnimationTarget {
+            easing: easing.into(),
+            ..self
+        }
+    }
+
+    /// Animate with a spring instead of a duration and easing
+    pub fn spring(self, spring: Spring) -> Self {
+        AnimationTarget {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
+    /// Pass through the given keyframes on the way to the target. Offsets are clamped between
+    /// 0.0 and 1.0 and keyframes with a NaN offset are dropped.
+    /// ```
+    /// # use leptos_animation::{easing, keyframes::Keyframe, AnimationTarget};
+    /// let target = AnimationTarget::new(1.0).keyframes(vec![
+    ///     Keyframe::new(f64::NAN, 2.0, easing::LINEAR),
+    ///     Keyframe::new(1.5, 3.0, easing::LINEAR),
+    ///     Keyframe {
+    ///         offset: -0.5,
+    ///         value: 4.0,
+    ///         easing: easing::LINEAR.into(),
+    ///     },
+    /// ]);
+    /// let offsets: Vec<_> = target.keyframes.iter().map(|keyframe| keyframe.offset).collect();
+    /// assert_eq!(offsets, [1.0, 0.0]);
+    /// ```
+    pub fn keyframes(self, keyframes: Vec<Keyframe<T>>) -> Self {
+        AnimationTarget {
+            keyframes: valid_keyframes(keyframes),
+            ..self
+        }
+    }
+
+    /// Set how to deal with running animations
+    pub fn mode(self, mode: AnimationMode) -> Self {
+        AnimationTarget { mode, ..self }
+    }
+
+    /// Start the animation after the given delay
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos_animation::AnimationTarget;
+    /// let _ = AnimationTarget::new(42.0).delay(Duration::from_millis(200));
+    /// ```
+    pub fn delay(self, delay: Duration) -> Self {
+        AnimationTarget { delay, ..self }
+    }
+
+    /// Play the animation the given number of times
+    /// ```
+    /// # use leptos_animation::{AnimationTarget, Repeat};
+    /// let _ = AnimationTarget::new(42.0).repeat(Repeat::Infinite);
+    /// ```
+    pub fn repeat(self, repeat: Repeat) -> Self {
+        AnimationTarget { repeat, ..self }
+    }
+
+    /// Set the direction in which the animation plays
+    /// ```
+    /// # use leptos_animation::{AnimationDirection, AnimationTarget, Repeat};
+    /// // Pulse back and forth once
+    /// let _ = AnimationTarget::new(42.0)
+    ///     .repeat(Repeat::Count(2.0))
+    ///     .direction(AnimationDirection::Alternate);
+    /// ```
+    pub fn direction(self, direction: AnimationDirection) -> Self {
+        AnimationTarget { direction, ..self }
+    }
+}
+
+impl<T> AnimationTarget<T> {
+    /// Adjust the animation to what the motion policy allows
+    pub(crate) fn with_motion_policy(self, motion_policy: MotionPolicy) -> Self {
+        match motion_policy {
+            MotionPolicy::Full => self,
+            MotionPolicy::Reduced => AnimationTarget {
+                duration: self.duration.min(REDUCED_MOTION_DURATION),
+                easing: LINEAR.into(),
+                spring: None,
+                keyframes: vec![],
+                repeat: Repeat::Count(1.0),
+                direction: AnimationDirection::Normal,
+                ..self
+            },
+            MotionPolicy::None => self.mode(AnimationMode::Snap),
         }
     }
+
+    /// The time from setting this target until the animation ends. Animations that repeat forever
+    /// count a single iteration.
+    pub(crate) fn play_duration(&self) -> Duration {
+        let duration = match self.spring {
+            Some(spring) => spring.settling_duration(),
+            None => self.duration,
+        };
+        let iterations = match self.repeat {
+            Repeat::Count(count) => count.max(0.0),
+            Repeat::Infinite => 1.0,
+        };
+        self.delay + duration.mul_f64(iterations)
+    }
+}
+
+impl<T: Default> Default for AnimationTarget<T> {
+    fn default() -> Self {
+        AnimationTarget::new(T::default())
+    }
+}
+
+impl<T> From<T> for AnimationTarget<T> {
+    fn from(target: T) -> Self {
+        AnimationTarget::new(target)
+    }
 }
 
 // Allow users to call .into() on a tuple of any combination of duration, easing & mode
 
 impl<T> From<(T, Duration)> for AnimationTarget<T> {
     fn from((target, duration): (T, Duration)) -> Self {
-        AnimationTarget {
-            target,
-            mode: DEFAULT_MODE,
-            duration,
-            easing: DEFAULT_EASING,
-        }
+        AnimationTarget::new(target).duration(duration)
     }
 }
 
-impl<T> From<(T, Easing)> for AnimationTarget<T> {
-    fn from((target, easing): (T, Easing)) -> Self {
-        AnimationTarget {
-            target,
-            mode: DEFAULT_MODE,
-            duration: DEFAULT_DURATION,
-            easing,
-        }
+impl<T, E: Into<Easing>> From<(T, E)> for AnimationTarget<T> {
+    fn from((target, easing): (T, E)) -> Self {
+        AnimationTarget::new(target).easing(easing)
     }
 }
 
 impl<T> From<(T, AnimationMode)> for AnimationTarget<T> {
     fn from((target, mode): (T, AnimationMode)) -> Self {
-        AnimationTarget {
-            target,
-            mode,
-            duration: DEFAULT_DURATION,
-            easing: DEFAULT_EASING,
-        }
+        AnimationTarget::new(target).mode(mode)
     }
 }
 
-impl<T> From<(T, Duration, Easing)> for AnimationTarget<T> {
-    fn from((target, duration, easing): (T, Duration, Easing)) -> Self {
-        AnimationTarget {
-            target,
-            mode: DEFAULT_MODE,
-            duration,
-            easing,
-        }
+impl<T, E: Into<Easing>> From<(T, Duration, E)> for AnimationTarget<T> {
+    fn from((target, duration, easing): (T, Duration, E)) -> Self {
+        AnimationTarget::new(target)
+            .duration(duration)
+            .easing(easing)
     }
 }
 
-impl<T> From<(T, Easing, AnimationMode)> for AnimationTarget<T> {
-    fn from((target, easing, mode): (T, Easing, AnimationMode)) -> Self {
-        AnimationTarget {
-            target,
-            mode,
-            duration: DEFAULT_DURATION,
-            easing,
-        }
+impl<T, E: Into<Easing>> From<(T, E, AnimationMode)> for AnimationTarget<T> {
+    fn from((target, easing, mode): (T, E, AnimationMode)) -> Self {
+        AnimationTarget::new(target).easing(easing).mode(mode)
     }
 }
 
 impl<T> From<(T, Duration, AnimationMode)> for AnimationTarget<T> {
     fn from((target, duration, mode): (T, Duration, AnimationMode)) -> Self {
-        AnimationTarget {
-            target,
-            mode,
-            duration,
-            easing: DEFAULT_EASING,
-        }
+        AnimationTarget::new(target).duration(duration).mode(mode)
     }
 }
 
-impl<T> From<(T, Duration, Easing, AnimationMode)> for AnimationTarget<T> {
-    fn from((target, duration, easing, mode): (T, Duration, Easing, AnimationMode)) -> Self {
-        AnimationTarget {
-            target,
-            mode,
-            duration,
-            easing,
-        }
+impl<T, E: Into<Easing>> From<(T, Duration, E, AnimationMode)> for AnimationTarget<T> {
+    fn from((target, duration, easing, mode): (T, Duration, E, AnimationMode)) -> Self {
+        AnimationTarget::new(target)
+            .duration(duration)
+            .easing(easing)
+            .mode(mode)
+    }
+}
+
+impl<T> From<(T, Spring)> for AnimationTarget<T> {
+    fn from((target, spring): (T, Spring)) -> Self {
+        AnimationTarget::new(target).spring(spring)
+    }
+}
+
+impl<T> From<(T, Spring, AnimationMode)> for AnimationTarget<T> {
+    fn from((target, spring, mode): (T, Spring, AnimationMode)) -> Self {
+        AnimationTarget::new(target).spring(spring).mode(mode)
     }
 }
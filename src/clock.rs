@@ -0,0 +1,93 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use instant::Instant;
+
+/// A `Clock` is the source of time for all animations in an [`AnimationContext`](crate::AnimationContext).
+/// By default the [`SystemClock`] is used. A different clock can be set with
+/// [`AnimationContext::set_clock()`](crate::AnimationContext::set_clock).
+pub trait Clock {
+    /// The current point in time as seen by the animation system
+    fn now(&self) -> Instant;
+}
+
+/// The default clock, reads the current time from the system
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves forward when [`advance()`](ManualClock::advance) is called. Clones of
+/// a `ManualClock` share the same time.
+///
+/// This is useful in tests where exact interpolated values need to be checked without waiting for
+/// real time to pass. Combined with [`AnimationContext::provide_with_custom_request_animation_frame()`](crate::AnimationContext::provide_with_custom_request_animation_frame)
+/// animation frames can be fired by hand:
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animated_signal, easing, tween_default, AnimationContext};
+/// # let runtime = create_runtime();
+/// let (context, animation_frame) =
+///     AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// let clock = ManualClock::new();
+/// context.set_clock(clock.clone());
+///
+/// let (value, set_value) = create_signal(0.0);
+/// let animated_value = create_animated_signal(
+///     move || (value.get(), Duration::from_secs(1), easing::LINEAR).into(),
+///     tween_default::<f64, f64>,
+/// );
+///
+/// set_value.set(100.0);
+/// clock.advance(Duration::from_millis(250));
+/// animation_frame();
+/// assert_eq!(animated_value.get_untracked(), 25.0);
+///
+/// clock.advance(Duration::from_secs(1));
+/// animation_frame();
+/// assert_eq!(animated_value.get_untracked(), 100.0);
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ManualClock {
+    now: Rc<Cell<Instant>>,
+}
+
+impl ManualClock {
+    /// Create a new clock, starting at the current system time
+    pub fn new() -> ManualClock {
+        ManualClock {
+            now: Rc::new(Cell::new(Instant::now())),
+        }
+    }
+
+    /// Create a new clock, starting at the given time
+    pub fn starting_at(now: Instant) -> ManualClock {
+        ManualClock {
+            now: Rc::new(Cell::new(now)),
+        }
+    }
+
+    /// Move the clock forward by the given duration
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        ManualClock::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+}
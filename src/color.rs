@@ -0,0 +1,796 @@
+//! Tween functions for colors, enabled with the `color` feature.
+//!
+//! Colors can be interpolated in different color spaces, each with their own look:
+//! * [`tween_srgb()`] mixes the red, green and blue components as they are stored, like most
+//!   browsers do by default
+//! * [`tween_linear_rgb()`] mixes physical light intensities, which keeps mixes bright
+//! * [`tween_hsl()`] & [`tween_hsv()`] rotate the hue along the shortest path around the color wheel
+//! * [`tween_oklab()`] mixes in a perceptually uniform color space, without muddy colors halfway
+//! * [`tween_oklch()`] rotates the hue in a perceptually uniform color space
+//!
+//! The tween functions accept any type that can be converted into a [`Color`], such as
+//! `(u8, u8, u8)` or `[u8; 4]` tuples and arrays, and always return a [`Color`].
+//! Colors can be parsed from and formatted as CSS color strings.
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use leptos::*;
+//! # use leptos_animation::{clock::ManualClock, color::{self, Color}, create_animated_signal, easing, AnimationContext};
+//! # let runtime = create_runtime();
+//! # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+//! # let clock = ManualClock::new();
+//! # context.set_clock(clock.clone());
+//! let (color, set_color) = create_signal("red".parse::<Color>().unwrap());
+//! let animated_color = create_animated_signal(
+//!     move || (color.get(), Duration::from_secs(1), easing::LINEAR).into(),
+//!     color::tween_hsl::<Color>,
+//! );
+//!
+//! set_color.set("#00f".parse().unwrap());
+//! clock.advance(Duration::from_millis(500));
+//! animation_frame();
+//! // Halfway from red to blue along the shortest path around the hue circle
+//! assert_eq!(animated_color.get_untracked().to_string(), "rgb(255, 0, 255)");
+//! # runtime.dispose();
+//! ```
+//!
+//! # Additive animations
+//!
+//! When animations overlap their results are added together in sRGB, see
+//! [`create_animated_signal()`](crate::create_animated_signal). Intermediate colors are therefore
+//! not clamped and can temporarily fall outside of the displayable range. They are only clamped when
+//! formatted as a string or converted to bytes. Each tween returns the exact start and end colors at
+//! the start and end of an animation so that finished animations cancel out without leaving a tint.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+use crate::{css::parse_angle, Animatable};
+
+/// Chroma or saturation below which a color is considered to be gray, making its hue meaningless
+const ACHROMATIC_THRESHOLD: f64 = 1e-6;
+
+/// A color in the sRGB color space with an alpha channel. All components are nominally between
+/// 0.0 and 1.0 but are not clamped, see [Additive animations](self#additive-animations).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Color {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    pub alpha: f64,
+}
+
+impl Color {
+    pub const TRANSPARENT: Color = Color::new(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Color = Color::new(0.0, 0.0, 0.0, 1.0);
+    pub const WHITE: Color = Color::new(1.0, 1.0, 1.0, 1.0);
+
+    pub const fn new(red: f64, green: f64, blue: f64, alpha: f64) -> Color {
+        Color {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// An opaque color from 8-bit red, green and blue components
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Color {
+        Color::new(
+            red as f64 / 255.0,
+            green as f64 / 255.0,
+            blue as f64 / 255.0,
+            1.0,
+        )
+    }
+
+    /// A color from red, green and blue light intensities between 0.0 and 1.0
+    pub fn from_linear_rgb([red, green, blue]: [f64; 3], alpha: f64) -> Color {
+        Color::new(
+            linear_to_srgb(red),
+            linear_to_srgb(green),
+            linear_to_srgb(blue),
+            alpha,
+        )
+    }
+
+    /// The red, green and blue light intensities of this color
+    pub fn to_linear_rgb(&self) -> [f64; 3] {
+        [
+            srgb_to_linear(self.red),
+            srgb_to_linear(self.green),
+            srgb_to_linear(self.blue),
+        ]
+    }
+
+    /// A color from a hue in degrees and a saturation and lightness between 0.0 and 1.0
+    pub fn from_hsl([hue, saturation, lightness]: [f64; 3], alpha: f64) -> Color {
+        let a = saturation * lightness.min(1.0 - lightness);
+        let f = |n: f64| {
+            let k = (n + hue / 30.0).rem_euclid(12.0);
+            lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        Color::new(f(0.0), f(8.0), f(4.0), alpha)
+    }
+
+    /// The hue in degrees and the saturation and lightness of this color
+    pub fn to_hsl(&self) -> [f64; 3] {
+        let (max, min) = self.max_min();
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        [self.hue(), saturation, lightness]
+    }
+
+    /// A color from a hue in degrees and a saturation and value between 0.0 and 1.0
+    pub fn from_hsv([hue, saturation, value]: [f64; 3], alpha: f64) -> Color {
+        let f = |n: f64| {
+            let k = (n + hue / 60.0).rem_euclid(6.0);
+            value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+        Color::new(f(5.0), f(3.0), f(1.0), alpha)
+    }
+
+    /// The hue in degrees and the saturation and value of this color
+    pub fn to_hsv(&self) -> [f64; 3] {
+        let (max, min) = self.max_min();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+        [self.hue(), saturation, max]
+    }
+
+    /// A color from OKLab lightness, green-red and blue-yellow components
+    pub fn from_oklab([lightness, a, b]: [f64; 3], alpha: f64) -> Color {
+        let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+        Color::from_linear_rgb(
+            [
+                4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+                -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+                -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+            ],
+            alpha,
+        )
+    }
+
+    /// The OKLab lightness, green-red and blue-yellow components of this color
+    pub fn to_oklab(&self) -> [f64; 3] {
+        let [red, green, blue] = self.to_linear_rgb();
+        let l = (0.4122214708 * red + 0.5363325363 * green + 0.0514459929 * blue).cbrt();
+        let m = (0.2119034982 * red + 0.6806995451 * green + 0.1073969566 * blue).cbrt();
+        let s = (0.0883024619 * red + 0.2817188376 * green + 0.6299787005 * blue).cbrt();
+        [
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        ]
+    }
+
+    /// A color from OKLCH lightness, chroma and hue in degrees
+    pub fn from_oklch([lightness, chroma, hue]: [f64; 3], alpha: f64) -> Color {
+        let (sin, cos) = hue.to_radians().sin_cos();
+        Color::from_oklab([lightness, chroma * cos, chroma * sin], alpha)
+    }
+
+    /// The OKLCH lightness, chroma and hue in degrees of this color
+    pub fn to_oklch(&self) -> [f64; 3] {
+        let [lightness, a, b] = self.to_oklab();
+        [
+            lightness,
+            a.hypot(b),
+            b.atan2(a).to_degrees().rem_euclid(360.0),
+        ]
+    }
+
+    /// The color clamped to the displayable range as 8-bit red, green, blue and alpha components
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+            .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    /// Format the color as a CSS hex color, `#rrggbb` or `#rrggbbaa` if it is transparent
+    pub fn to_hex(&self) -> String {
+        let [red, green, blue, alpha] = self.to_rgba8();
+        if alpha == 255 {
+            format!("#{red:02x}{green:02x}{blue:02x}")
+        } else {
+            format!("#{red:02x}{green:02x}{blue:02x}{alpha:02x}")
+        }
+    }
+
+    fn max_min(&self) -> (f64, f64) {
+        let max = self.red.max(self.green).max(self.blue);
+        let min = self.red.min(self.green).min(self.blue);
+        (max, min)
+    }
+
+    fn hue(&self) -> f64 {
+        let (max, min) = self.max_min();
+        let delta = max - min;
+        if delta == 0.0 {
+            0.0
+        } else if max == self.red {
+            60.0 * ((self.green - self.blue) / delta).rem_euclid(6.0)
+        } else if max == self.green {
+            60.0 * ((self.blue - self.red) / delta + 2.0)
+        } else {
+            60.0 * ((self.red - self.green) / delta + 4.0)
+        }
+    }
+}
+
+/// Interpolate in sRGB, the way colors are stored
+pub fn tween_srgb<C: Clone + Into<Color>>(from: &C, to: &C, progress: f64) -> Color {
+    tween_with(from, to, progress, |from, to| {
+        Color::new(
+            lerp(from.red, to.red, progress),
+            lerp(from.green, to.green, progress),
+            lerp(from.blue, to.blue, progress),
+            lerp(from.alpha, to.alpha, progress),
+        )
+    })
+}
+
+/// Interpolate the light intensities in linear RGB
+pub fn tween_linear_rgb<C: Clone + Into<Color>>(from: &C, to: &C, progress: f64) -> Color {
+    tween_with(from, to, progress, |from, to| {
+        Color::from_linear_rgb(
+            lerp3(from.to_linear_rgb(), to.to_linear_rgb(), progress),
+            lerp(from.alpha, to.alpha, progress),
+        )
+    })
+}
+
+/// Interpolate in HSL, the hue takes the shortest path around the color wheel
+pub fn tween_hsl<C: Clone + Into<Color>>(from: &C, to: &C, progress: f64) -> Color {
+    tween_with(from, to, progress, |from, to| {
+        Color::from_hsl(
+            lerp_polar(from.to_hsl(), to.to_hsl(), 1, 0, progress),
+            lerp(from.alpha, to.alpha, progress),
+        )
+    })
+}
+
+/// Interpolate in HSV, the hue takes the shortest path around the color wheel
+pub fn tween_hsv<C: Clone + Into<Color>>(from: &C, to: &C, progress: f64) -> Color {
+    tween_with(from, to, progress, |from, to| {
+        Color::from_hsv(
+            lerp_polar(from.to_hsv(), to.to_hsv(), 1, 0, progress),
+            lerp(from.alpha, to.alpha, progress),
+        )
+    })
+}
+
+/// Interpolate in the perceptually uniform OKLab color space
+pub fn tween_oklab<C: Clone + Into<Color>>(from: &C, to: &C, progress: f64) -> Color {
+    tween_with(from, to, progress, |from, to| {
+        Color::from_oklab(
+            lerp3(from.to_oklab(), to.to_oklab(), progress),
+            lerp(from.alpha, to.alpha, progress),
+        )
+    })
+}
+
+/// Interpolate in the perceptually uniform OKLCH color space, the hue takes the shortest path
+/// around the color wheel
+pub fn tween_oklch<C: Clone + Into<Color>>(from: &C, to: &C, progress: f64) -> Color {
+    tween_with(from, to, progress, |from, to| {
+        Color::from_oklch(
+            lerp_polar(from.to_oklch(), to.to_oklch(), 1, 2, progress),
+            lerp(from.alpha, to.alpha, progress),
+        )
+    })
+}
+
+fn tween_with<C: Clone + Into<Color>>(
+    from: &C,
+    to: &C,
+    progress: f64,
+    mix: impl Fn(&Color, &Color) -> Color,
+) -> Color {
+    let from: Color = from.clone().into();
+    let to: Color = to.clone().into();
+
+    // Mixing converts to another color space and back, which is off by a rounding error. Return
+    // the end points as is so a finished animation shows exactly the target color.
+    if progress == 0.0 {
+        from
+    } else if progress == 1.0 {
+        to
+    } else {
+        mix(&from, &to)
+    }
+}
+
+fn lerp(from: f64, to: f64, progress: f64) -> f64 {
+    from + (to - from) * progress
+}
+
+fn lerp3(from: [f64; 3], to: [f64; 3], progress: f64) -> [f64; 3] {
+    [0, 1, 2].map(|i| lerp(from[i], to[i], progress))
+}
+
+/// Interpolate components of a color space with a hue. The hue of a gray color is meaningless, so
+/// the hue of the other color is used instead.
+fn lerp_polar(
+    mut from: [f64; 3],
+    mut to: [f64; 3],
+    chroma: usize,
+    hue: usize,
+    progress: f64,
+) -> [f64; 3] {
+    if from[chroma] < ACHROMATIC_THRESHOLD {
+        from[hue] = to[hue];
+    } else if to[chroma] < ACHROMATIC_THRESHOLD {
+        to[hue] = from[hue];
+    }
+
+    let mut result = lerp3(from, to, progress);
+    let mut difference = (to[hue] - from[hue]).rem_euclid(360.0);
+    if difference > 180.0 {
+        difference -= 360.0;
+    }
+    result[hue] = (from[hue] + difference * progress).rem_euclid(360.0);
+    result
+}
+
+fn srgb_to_linear(component: f64) -> f64 {
+    let abs = component.abs();
+    let linear = if abs <= 0.04045 {
+        abs / 12.92
+    } else {
+        ((abs + 0.055) / 1.055).powf(2.4)
+    };
+    linear.copysign(component)
+}
+
+fn linear_to_srgb(component: f64) -> f64 {
+    let abs = component.abs();
+    let srgb = if abs <= 0.0031308 {
+        abs * 12.92
+    } else {
+        1.055 * abs.powf(1.0 / 2.4) - 0.055
+    };
+    srgb.copysign(component)
+}
+
+impl Add for Color {
+    type Output = Color;
+
+    fn add(self, rhs: Color) -> Color {
+        Color::new(
+            self.red + rhs.red,
+            self.green + rhs.green,
+            self.blue + rhs.blue,
+            self.alpha + rhs.alpha,
+        )
+    }
+}
+
+impl Sub for Color {
+    type Output = Color;
+
+    fn sub(self, rhs: Color) -> Color {
+        Color::new(
+            self.red - rhs.red,
+            self.green - rhs.green,
+            self.blue - rhs.blue,
+            self.alpha - rhs.alpha,
+        )
+    }
+}
+
+impl Mul<f64> for Color {
+    type Output = Color;
+
+    fn mul(self, rhs: f64) -> Color {
+        Color::new(
+            self.red * rhs,
+            self.green * rhs,
+            self.blue * rhs,
+            self.alpha * rhs,
+        )
+    }
+}
+
+/// Colors are interpolated in sRGB
+impl Animatable for Color {
+    type Delta = Color;
+
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        tween_srgb(self, to, progress)
+    }
+
+    fn delta(&self, other: &Self) -> Self::Delta {
+        *self - *other
+    }
+
+    fn add_delta(&self, delta: &Self::Delta) -> Self {
+        *self + *delta
+    }
+
+    fn zero_delta() -> Self::Delta {
+        Color::TRANSPARENT
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, green, blue): (u8, u8, u8)) -> Self {
+        Color::rgb(red, green, blue)
+    }
+}
+
+impl From<[u8; 3]> for Color {
+    fn from([red, green, blue]: [u8; 3]) -> Self {
+        Color::rgb(red, green, blue)
+    }
+}
+
+impl From<[u8; 4]> for Color {
+    fn from([red, green, blue, alpha]: [u8; 4]) -> Self {
+        Color {
+            alpha: alpha as f64 / 255.0,
+            ..Color::rgb(red, green, blue)
+        }
+    }
+}
+
+impl From<[f32; 3]> for Color {
+    fn from([red, green, blue]: [f32; 3]) -> Self {
+        Color::new(red as f64, green as f64, blue as f64, 1.0)
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from([red, green, blue, alpha]: [f32; 4]) -> Self {
+        Color::new(red as f64, green as f64, blue as f64, alpha as f64)
+    }
+}
+
+impl From<[f64; 3]> for Color {
+    fn from([red, green, blue]: [f64; 3]) -> Self {
+        Color::new(red, green, blue, 1.0)
+    }
+}
+
+impl From<[f64; 4]> for Color {
+    fn from([red, green, blue, alpha]: [f64; 4]) -> Self {
+        Color::new(red, green, blue, alpha)
+    }
+}
+
+/// Formats the color as a CSS `rgb()` color, or `rgba()` if it is transparent
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let [red, green, blue, _] = self.to_rgba8();
+        let alpha = (self.alpha.clamp(0.0, 1.0) * 1000.0).round() / 1000.0;
+        if alpha == 1.0 {
+            write!(f, "rgb({red}, {green}, {blue})")
+        } else {
+            write!(f, "rgba({red}, {green}, {blue}, {alpha})")
+        }
+    }
+}
+
+/// The error returned when a string is not a valid CSS color
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid CSS color: {:?}", self.input)
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// Parses a CSS color: a named color, a hex color or one of the `rgb()`, `rgba()`, `hsl()`,
+/// `hsla()`, `hwb()`, `oklab()` and `oklch()` functions.
+///
+/// ```
+/// # use leptos_animation::color::Color;
+/// let color: Color = "hsl(120deg 100% 25% / 50%)".parse().unwrap();
+/// assert_eq!(color.to_hex(), "#00800080");
+/// assert_eq!("rgba(0, 128, 0, .5)".parse::<Color>().unwrap().to_hex(), "#00800080");
+/// ```
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim().to_ascii_lowercase();
+        let color = if let Some(hex) = input.strip_prefix('#') {
+            parse_hex(hex)
+        } else if let Some((name, arguments)) = input
+            .strip_suffix(')')
+            .and_then(|input| input.split_once('('))
+        {
+            parse_function(name.trim(), arguments)
+        } else {
+            named_color(&input)
+        };
+        color.ok_or(ParseColorError {
+            input: s.to_string(),
+        })
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    let digits = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|digit| digit as u8))
+        .collect::<Option<Vec<_>>>()?;
+    let [red, green, blue, alpha] = match digits[..] {
+        [r, g, b] => [r * 17, g * 17, b * 17, 255],
+        [r, g, b, a] => [r * 17, g * 17, b * 17, a * 17],
+        [r1, r2, g1, g2, b1, b2] => [r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2, 255],
+        [r1, r2, g1, g2, b1, b2, a1, a2] => {
+            [r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2, a1 * 16 + a2]
+        }
+        _ => return None,
+    };
+    Some([red, green, blue, alpha].into())
+}
+
+fn parse_function(name: &str, arguments: &str) -> Option<Color> {
+    let (components, alpha) = match arguments.split_once('/') {
+        Some((components, alpha)) => (components, Some(alpha.trim())),
+        None => (arguments, None),
+    };
+    let mut components: Vec<&str> = components
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|component| !component.is_empty())
+        .collect();
+
+    // The legacy comma separated syntax has the alpha as fourth component
+    let alpha = match alpha {
+        Some(alpha) => Some(alpha),
+        None if components.len() == 4 => components.pop(),
+        None => None,
+    };
+    let alpha = match alpha {
+        Some(alpha) => parse_number(alpha, 1.0)?,
+        None => 1.0,
+    };
+    let [first, second, third] = components[..] else {
+        return None;
+    };
+
+    match name {
+        "rgb" | "rgba" => Some(Color::new(
+            parse_number(first, 255.0)? / 255.0,
+            parse_number(second, 255.0)? / 255.0,
+            parse_number(third, 255.0)? / 255.0,
+            alpha,
+        )),
+        "hsl" | "hsla" => Some(Color::from_hsl(
+            [
+                parse_hue(first)?,
+                parse_number(second, 100.0)? / 100.0,
+                parse_number(third, 100.0)? / 100.0,
+            ],
+            alpha,
+        )),
+        "hwb" => {
+            let hue = parse_hue(first)?;
+            let whiteness = parse_number(second, 100.0)? / 100.0;
+            let blackness = parse_number(third, 100.0)? / 100.0;
+            if whiteness + blackness >= 1.0 {
+                let gray = whiteness / (whiteness + blackness);
+                Some(Color::new(gray, gray, gray, alpha))
+            } else {
+                let value = 1.0 - blackness;
+                Some(Color::from_hsv(
+                    [hue, 1.0 - whiteness / value, value],
+                    alpha,
+                ))
+            }
+        }
+        "oklab" => Some(Color::from_oklab(
+            [
+                parse_number(first, 1.0)?,
+                parse_number(second, 0.4)?,
+                parse_number(third, 0.4)?,
+            ],
+            alpha,
+        )),
+        "oklch" => Some(Color::from_oklch(
+            [
+                parse_number(first, 1.0)?,
+                parse_number(second, 0.4)?,
+                parse_hue(third)?,
+            ],
+            alpha,
+        )),
+        _ => None,
+    }
+}
+
+/// Parse a number or a percentage where 100% equals `percentage_scale`
+fn parse_number(number: &str, percentage_scale: f64) -> Option<f64> {
+    if number == "none" {
+        Some(0.0)
+    } else if let Some(percentage) = number.strip_suffix('%') {
+        Some(percentage.parse::<f64>().ok()? / 100.0 * percentage_scale)
+    } else {
+        number.parse().ok()
+    }
+}
+
+/// Parse an angle in degrees
+fn parse_hue(hue: &str) -> Option<f64> {
+    if hue == "none" {
+        Some(0.0)
+    } else {
+        parse_angle(hue)
+    }
+}
+
+fn named_color(name: &str) -> Option<Color> {
+    if name == "transparent" {
+        return Some(Color::TRANSPARENT);
+    }
+    let index = NAMED_COLORS
+        .binary_search_by_key(&name, |&(color_name, _)| color_name)
+        .ok()?;
+    let [_, red, green, blue] = NAMED_COLORS[index].1.to_be_bytes();
+    Some(Color::rgb(red, green, blue))
+}
+
+/// All named CSS colors, sorted by name
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
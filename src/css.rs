@@ -0,0 +1,713 @@
+//! CSS values that can be animated and rendered as CSS strings.
+//!
+//! * [`Length`] is a length in one or more units such as `px`, `%` or `rem`. Lengths in different
+//!   units are combined with `calc()` so that they can be animated between without knowing the
+//!   layout of the page. It supports `tween_default` as well as [`create_animation()`](crate::create_animation).
+//! * [`Transform`] is a list of transform functions, animated the way browsers animate the CSS
+//!   `transform` property.
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use leptos::*;
+//! # use leptos_animation::{clock::ManualClock, create_animation, css::Transform, easing, AnimationContext};
+//! # let runtime = create_runtime();
+//! # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+//! # let clock = ManualClock::new();
+//! # context.set_clock(clock.clone());
+//! let (transform, set_transform) = create_signal(Transform::default());
+//! let animated_transform = create_animation::<Transform>(move || {
+//!     (transform.get(), Duration::from_secs(1), easing::LINEAR).into()
+//! });
+//!
+//! set_transform.set("translate(10px, 2rem) rotate(30deg)".parse().unwrap());
+//! clock.advance(Duration::from_millis(500));
+//! animation_frame();
+//! assert_eq!(
+//!     animated_transform.get_untracked().to_string(),
+//!     "translate(5px, 1rem) rotate(15deg)"
+//! );
+//! # runtime.dispose();
+//! ```
+
+use std::error::Error;
+use std::f64::consts::PI;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+use crate::Animatable;
+
+/// A CSS length as a sum of lengths in different units, for example `calc(10px + 50%)`.
+///
+/// ```
+/// # use leptos_animation::{css::Length, tween_default};
+/// let from = Length::px(100.0);
+/// let to = Length::percent(50.0);
+/// assert_eq!(tween_default::<Length, Length>(&from, &to, 0.5).to_string(), "calc(50px + 25%)");
+/// assert_eq!("2.5rem".parse::<Length>().unwrap(), Length::rem(2.5));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Length {
+    pub px: f64,
+    pub percent: f64,
+    pub em: f64,
+    pub rem: f64,
+    pub vw: f64,
+    pub vh: f64,
+}
+
+impl Length {
+    pub const ZERO: Length = Length {
+        px: 0.0,
+        percent: 0.0,
+        em: 0.0,
+        rem: 0.0,
+        vw: 0.0,
+        vh: 0.0,
+    };
+
+    pub const fn px(px: f64) -> Length {
+        Length { px, ..Length::ZERO }
+    }
+
+    pub const fn percent(percent: f64) -> Length {
+        Length {
+            percent,
+            ..Length::ZERO
+        }
+    }
+
+    pub const fn em(em: f64) -> Length {
+        Length { em, ..Length::ZERO }
+    }
+
+    pub const fn rem(rem: f64) -> Length {
+        Length {
+            rem,
+            ..Length::ZERO
+        }
+    }
+
+    pub const fn vw(vw: f64) -> Length {
+        Length { vw, ..Length::ZERO }
+    }
+
+    pub const fn vh(vh: f64) -> Length {
+        Length { vh, ..Length::ZERO }
+    }
+
+    /// All lengths with their unit, including lengths of zero
+    fn terms(&self) -> [(f64, &'static str); 6] {
+        [
+            (self.px, "px"),
+            (self.percent, "%"),
+            (self.em, "em"),
+            (self.rem, "rem"),
+            (self.vw, "vw"),
+            (self.vh, "vh"),
+        ]
+    }
+}
+
+impl Add for Length {
+    type Output = Length;
+
+    fn add(self, rhs: Length) -> Length {
+        Length {
+            px: self.px + rhs.px,
+            percent: self.percent + rhs.percent,
+            em: self.em + rhs.em,
+            rem: self.rem + rhs.rem,
+            vw: self.vw + rhs.vw,
+            vh: self.vh + rhs.vh,
+        }
+    }
+}
+
+impl Sub for Length {
+    type Output = Length;
+
+    fn sub(self, rhs: Length) -> Length {
+        self + rhs * -1.0
+    }
+}
+
+impl Mul<f64> for Length {
+    type Output = Length;
+
+    fn mul(self, rhs: f64) -> Length {
+        Length {
+            px: self.px * rhs,
+            percent: self.percent * rhs,
+            em: self.em * rhs,
+            rem: self.rem * rhs,
+            vw: self.vw * rhs,
+            vh: self.vh * rhs,
+        }
+    }
+}
+
+impl Animatable for Length {
+    type Delta = Length;
+
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        *self + (*to - *self) * progress
+    }
+
+    fn delta(&self, other: &Self) -> Self::Delta {
+        *self - *other
+    }
+
+    fn add_delta(&self, delta: &Self::Delta) -> Self {
+        *self + *delta
+    }
+
+    fn zero_delta() -> Self::Delta {
+        Length::ZERO
+    }
+}
+
+/// Formats a single length such as `10px`, or a `calc()` of all units that are used
+impl Display for Length {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let terms: Vec<_> = self
+            .terms()
+            .into_iter()
+            .map(|(value, unit)| (round(value), unit))
+            .filter(|(value, _)| *value != 0.0)
+            .collect();
+
+        match terms[..] {
+            [] => write!(f, "0px"),
+            [(value, unit)] => write!(f, "{value}{unit}"),
+            [(first, first_unit), ref rest @ ..] => {
+                write!(f, "calc({first}{first_unit}")?;
+                for (value, unit) in rest {
+                    let sign = if *value < 0.0 { '-' } else { '+' };
+                    write!(f, " {sign} {}{unit}", value.abs())?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// Parses a length such as `10px` or `-2.5rem`, or a `calc()` that adds and subtracts lengths
+impl FromStr for Length {
+    type Err = ParseCssError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_length(s).ok_or_else(|| ParseCssError::new(s))
+    }
+}
+
+/// A single CSS transform function. Angles are in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransformFunction {
+    Translate(Length, Length),
+    Rotate(f64),
+    Scale(f64, f64),
+    Skew(f64, f64),
+}
+
+impl TransformFunction {
+    /// The function of the same kind that does not transform anything
+    fn identity(&self) -> TransformFunction {
+        match self {
+            TransformFunction::Translate(..) => {
+                TransformFunction::Translate(Length::ZERO, Length::ZERO)
+            }
+            TransformFunction::Rotate(_) => TransformFunction::Rotate(0.0),
+            TransformFunction::Scale(..) => TransformFunction::Scale(1.0, 1.0),
+            TransformFunction::Skew(..) => TransformFunction::Skew(0.0, 0.0),
+        }
+    }
+
+    fn same_kind(&self, other: &TransformFunction) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Combine two functions of the same kind component by component
+    fn zip(
+        &self,
+        other: &TransformFunction,
+        length: impl Fn(Length, Length) -> Length,
+        number: impl Fn(f64, f64) -> f64,
+    ) -> TransformFunction {
+        use TransformFunction::*;
+        match (*self, *other) {
+            (Translate(x1, y1), Translate(x2, y2)) => Translate(length(x1, x2), length(y1, y2)),
+            (Rotate(a1), Rotate(a2)) => Rotate(number(a1, a2)),
+            (Scale(x1, y1), Scale(x2, y2)) => Scale(number(x1, x2), number(y1, y2)),
+            (Skew(x1, y1), Skew(x2, y2)) => Skew(number(x1, x2), number(y1, y2)),
+            _ => *self,
+        }
+    }
+
+    fn interpolate(&self, to: &TransformFunction, progress: f64) -> TransformFunction {
+        self.zip(
+            to,
+            |from, to| from + (to - from) * progress,
+            |from, to| from + (to - from) * progress,
+        )
+    }
+
+    fn matrix(&self) -> Matrix {
+        match *self {
+            TransformFunction::Translate(x, y) => Matrix {
+                e: x,
+                f: y,
+                ..Matrix::IDENTITY
+            },
+            TransformFunction::Rotate(angle) => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                Matrix {
+                    a: cos,
+                    b: sin,
+                    c: -sin,
+                    d: cos,
+                    ..Matrix::IDENTITY
+                }
+            }
+            TransformFunction::Scale(x, y) => Matrix {
+                a: x,
+                d: y,
+                ..Matrix::IDENTITY
+            },
+            TransformFunction::Skew(x, y) => Matrix {
+                b: y.to_radians().tan(),
+                c: x.to_radians().tan(),
+                ..Matrix::IDENTITY
+            },
+        }
+    }
+}
+
+impl Display for TransformFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            TransformFunction::Translate(x, y) => write!(f, "translate({x}, {y})"),
+            TransformFunction::Rotate(angle) => write!(f, "rotate({}deg)", round(angle)),
+            TransformFunction::Scale(x, y) => write!(f, "scale({}, {})", round(x), round(y)),
+            TransformFunction::Skew(x, y) => {
+                write!(f, "skew({}deg, {}deg)", round(x), round(y))
+            }
+        }
+    }
+}
+
+/// A list of transform functions, the value of the CSS `transform` property.
+///
+/// Transforms are interpolated the way browsers interpolate them:
+/// * Functions of the same kind at the same position in both lists are interpolated with each
+///   other. When one list is longer the other list is extended with functions that don't transform.
+/// * Once the kinds of functions no longer match, the remaining functions of both lists are
+///   combined into a matrix. The matrices are decomposed into a translation, rotation, skew and
+///   scale which are interpolated instead. The rotation takes the shortest way around.
+///
+/// Lengths other than pixels are carried through the matrix as is. Percentages are relative to
+/// the size of the element along their own axis, so rotating a percentage translation onto the
+/// other axis is only exact for square elements.
+///
+/// ```
+/// # use leptos_animation::{css::{Length, Transform}, Animatable};
+/// let from = Transform::default().rotate(90.0);
+/// let to = Transform::default().scale(2.0, 2.0);
+/// assert_eq!(from.interpolate(&to, 0.5).to_string(), "translate(0px, 0px) rotate(45deg) skew(0deg, 0deg) scale(1.5, 1.5)");
+///
+/// let translate = Transform::default().translate(Length::px(10.0), Length::percent(50.0));
+/// assert_eq!(translate.to_string(), "translate(10px, 50%)");
+/// assert_eq!("matrix(1, 0, 0, 1, 10, 0)".parse::<Transform>().unwrap().to_string(),
+///     "translate(10px, 0px) rotate(0deg) skew(0deg, 0deg) scale(1, 1)");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Transform {
+    pub functions: Vec<TransformFunction>,
+}
+
+impl Transform {
+    /// Append a translation
+    pub fn translate(mut self, x: Length, y: Length) -> Transform {
+        self.functions.push(TransformFunction::Translate(x, y));
+        self
+    }
+
+    /// Append a clockwise rotation in degrees
+    pub fn rotate(mut self, angle: f64) -> Transform {
+        self.functions.push(TransformFunction::Rotate(angle));
+        self
+    }
+
+    /// Append a scaling
+    pub fn scale(mut self, x: f64, y: f64) -> Transform {
+        self.functions.push(TransformFunction::Scale(x, y));
+        self
+    }
+
+    /// Append a skew along both axes in degrees
+    pub fn skew(mut self, x: f64, y: f64) -> Transform {
+        self.functions.push(TransformFunction::Skew(x, y));
+        self
+    }
+
+    fn same_kinds(&self, other: &Transform) -> bool {
+        self.functions.len() == other.functions.len()
+            && self
+                .functions
+                .iter()
+                .zip(&other.functions)
+                .all(|(a, b)| a.same_kind(b))
+    }
+
+    fn matrix(functions: &[TransformFunction]) -> Matrix {
+        functions.iter().fold(Matrix::IDENTITY, |matrix, function| {
+            matrix.multiply(&function.matrix())
+        })
+    }
+}
+
+/// The difference between two transforms
+///
+/// A difference between functions can only be added to a transform with the same kinds of
+/// functions. Added to any other transform it replaces that transform with the transform the
+/// difference was taken from, the same as when the transforms had different functions to begin
+/// with. This way the most recent animation wins when overlapping animations change the kinds of
+/// functions.
+/// ```
+/// # use leptos_animation::{css::Transform, Animatable};
+/// let base = Transform::default().rotate(90.0);
+/// let delta = Transform::default().rotate(45.0).delta(&base);
+/// assert_eq!(base.add_delta(&delta), Transform::default().rotate(45.0));
+///
+/// let scaled = Transform::default().scale(2.0, 2.0);
+/// assert_eq!(scaled.add_delta(&delta), Transform::default().rotate(45.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransformDelta {
+    /// Both transforms have the same kinds of functions, these are the differences between each
+    /// of the functions of `from` and the other transform
+    Functions {
+        differences: Vec<TransformFunction>,
+        from: Transform,
+    },
+
+    /// The transforms have different functions, adding this difference replaces the transform
+    Replace(Transform),
+}
+
+impl Animatable for Transform {
+    type Delta = TransformDelta;
+
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        // Functions that don't match are decomposed from a matrix, which turns them into different
+        // functions with rounding errors. Return the end points as is, so a finished animation has
+        // exactly the functions of its target.
+        if progress == 0.0 {
+            return self.clone();
+        } else if progress == 1.0 {
+            return to.clone();
+        }
+
+        let (from, to) = (&self.functions, &to.functions);
+        let matching = from
+            .iter()
+            .zip(to)
+            .take_while(|(from, to)| from.same_kind(to))
+            .count();
+
+        let mut functions: Vec<_> = from
+            .iter()
+            .zip(to)
+            .take(matching)
+            .map(|(from, to)| from.interpolate(to, progress))
+            .collect();
+
+        if matching == from.len().min(to.len()) {
+            // Extend the shorter list with functions that don't transform
+            let identity = |function: &TransformFunction| (function.identity(), *function);
+            let rest = from[matching..].iter().map(|from| (*from, from.identity()));
+            let rest = rest.chain(to[matching..].iter().map(identity));
+            functions.extend(rest.map(|(from, to)| from.interpolate(&to, progress)));
+        } else {
+            let from = Transform::matrix(&from[matching..]).decompose();
+            let mut to = Transform::matrix(&to[matching..]).decompose();
+
+            // Rotate the shortest way around
+            if let (TransformFunction::Rotate(from), TransformFunction::Rotate(to)) =
+                (from[1], &mut to[1])
+            {
+                if *to - from > 180.0 {
+                    *to -= 360.0;
+                } else if from - *to > 180.0 {
+                    *to += 360.0;
+                }
+            }
+            functions.extend(
+                from.iter()
+                    .zip(&to)
+                    .map(|(from, to)| from.interpolate(to, progress)),
+            );
+        }
+        Transform { functions }
+    }
+
+    fn delta(&self, other: &Self) -> Self::Delta {
+        if self.same_kinds(other) {
+            TransformDelta::Functions {
+                differences: self
+                    .functions
+                    .iter()
+                    .zip(&other.functions)
+                    .map(|(value, other)| value.zip(other, |a, b| a - b, |a, b| a - b))
+                    .collect(),
+                from: self.clone(),
+            }
+        } else {
+            TransformDelta::Replace(self.clone())
+        }
+    }
+
+    fn add_delta(&self, delta: &Self::Delta) -> Self {
+        match delta {
+            // The zero difference
+            TransformDelta::Functions { differences, .. } if differences.is_empty() => self.clone(),
+            TransformDelta::Functions { differences, from } if self.same_kinds(from) => Transform {
+                functions: self
+                    .functions
+                    .iter()
+                    .zip(differences)
+                    .map(|(value, delta)| value.zip(delta, |a, b| a + b, |a, b| a + b))
+                    .collect(),
+            },
+            TransformDelta::Functions { from, .. } | TransformDelta::Replace(from) => from.clone(),
+        }
+    }
+
+    fn zero_delta() -> Self::Delta {
+        TransformDelta::Functions {
+            differences: vec![],
+            from: Transform::default(),
+        }
+    }
+}
+
+/// Formats the transform as a list of functions, or `none` if there are no functions
+impl Display for Transform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.functions.is_empty() {
+            return write!(f, "none");
+        }
+        for (i, function) in self.functions.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{function}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a CSS transform: `none` or a list of `translate()`, `translateX()`, `translateY()`,
+/// `rotate()`, `scale()`, `scaleX()`, `scaleY()`, `skew()`, `skewX()`, `skewY()` and `matrix()`
+/// functions. A matrix is decomposed into a translation, rotation, skew and scale.
+impl FromStr for Transform {
+    type Err = ParseCssError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_transform(s).ok_or_else(|| ParseCssError::new(s))
+    }
+}
+
+/// A 2D transformation matrix in the form of the CSS `matrix(a, b, c, d, e, f)` function
+#[derive(Clone, Copy)]
+struct Matrix {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: Length,
+    f: Length,
+}
+
+impl Matrix {
+    const IDENTITY: Matrix = Matrix {
+        a: 1.0,
+        b: 0.0,
+        c: 0.0,
+        d: 1.0,
+        e: Length::ZERO,
+        f: Length::ZERO,
+    };
+
+    fn multiply(&self, other: &Matrix) -> Matrix {
+        Matrix {
+            a: self.a * other.a + self.c * other.b,
+            b: self.b * other.a + self.d * other.b,
+            c: self.a * other.c + self.c * other.d,
+            d: self.b * other.c + self.d * other.d,
+            e: other.e * self.a + other.f * self.c + self.e,
+            f: other.e * self.b + other.f * self.d + self.f,
+        }
+    }
+
+    /// Decompose into `translate() rotate() skew() scale()` functions
+    fn decompose(&self) -> [TransformFunction; 4] {
+        let scale_x = self.a.hypot(self.b);
+        let determinant = self.a * self.d - self.b * self.c;
+        let (rotation, scale_y, skew) = if scale_x == 0.0 {
+            (0.0, self.c.hypot(self.d), 0.0)
+        } else {
+            let rotation = self.b.atan2(self.a).to_degrees();
+            let scale_y = determinant / scale_x;
+            let skew = if determinant == 0.0 {
+                0.0
+            } else {
+                ((self.a * self.c + self.b * self.d) / determinant)
+                    .atan()
+                    .to_degrees()
+            };
+            (rotation, scale_y, skew)
+        };
+        [
+            TransformFunction::Translate(self.e, self.f),
+            TransformFunction::Rotate(rotation),
+            TransformFunction::Skew(skew, 0.0),
+            TransformFunction::Scale(scale_x, scale_y),
+        ]
+    }
+}
+
+/// The error returned when a string is not a valid CSS value
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseCssError {
+    input: String,
+}
+
+impl ParseCssError {
+    fn new(input: &str) -> ParseCssError {
+        ParseCssError {
+            input: input.to_string(),
+        }
+    }
+}
+
+impl Display for ParseCssError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid CSS value: {:?}", self.input)
+    }
+}
+
+impl Error for ParseCssError {}
+
+/// Round to a precision that is invisible on screen, to keep CSS strings short
+fn round(value: f64) -> f64 {
+    // Adding 0.0 turns -0.0 into 0.0
+    (value * 1000.0).round() / 1000.0 + 0.0
+}
+
+fn parse_length(length: &str) -> Option<Length> {
+    let length = length.trim();
+    if let Some(calc) = length
+        .strip_prefix("calc(")
+        .and_then(|calc| calc.strip_suffix(')'))
+    {
+        let mut terms = calc.split_whitespace();
+        let mut sum = parse_length(terms.next()?)?;
+        while let Some(operator) = terms.next() {
+            let term = parse_length(terms.next()?)?;
+            sum = match operator {
+                "+" => sum + term,
+                "-" => sum - term,
+                _ => return None,
+            };
+        }
+        return Some(sum);
+    }
+
+    let split = length
+        .find(|c: char| c != '.' && c != '-' && c != '+' && !c.is_ascii_digit())
+        .unwrap_or(length.len());
+    let (number, unit) = length.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    match unit.to_ascii_lowercase().as_str() {
+        "px" => Some(Length::px(number)),
+        "%" => Some(Length::percent(number)),
+        "em" => Some(Length::em(number)),
+        "rem" => Some(Length::rem(number)),
+        "vw" => Some(Length::vw(number)),
+        "vh" => Some(Length::vh(number)),
+        "" if number == 0.0 => Some(Length::ZERO),
+        _ => None,
+    }
+}
+
+/// Parse an angle in degrees, numbers without a unit are in degrees
+pub(crate) fn parse_angle(angle: &str) -> Option<f64> {
+    let (number, scale) = if let Some(number) = angle.strip_suffix("deg") {
+        (number, 1.0)
+    } else if let Some(number) = angle.strip_suffix("grad") {
+        (number, 0.9)
+    } else if let Some(number) = angle.strip_suffix("rad") {
+        (number, 180.0 / PI)
+    } else if let Some(number) = angle.strip_suffix("turn") {
+        (number, 360.0)
+    } else {
+        (angle, 1.0)
+    };
+    Some(number.trim().parse::<f64>().ok()? * scale)
+}
+
+fn parse_transform(transform: &str) -> Option<Transform> {
+    let mut rest = transform.trim();
+    let mut result = Transform::default();
+    if rest.eq_ignore_ascii_case("none") {
+        return Some(result);
+    }
+
+    while !rest.is_empty() {
+        let (name, after_name) = rest.split_once('(')?;
+        let (arguments, after_arguments) = after_name.split_once(')')?;
+        rest = after_arguments.trim_start();
+
+        let arguments: Vec<&str> = arguments
+            .split(',')
+            .map(str::trim)
+            .filter(|argument| !argument.is_empty())
+            .collect();
+        let number = |i: usize| arguments.get(i)?.parse::<f64>().ok();
+        let length = |i: usize| parse_length(arguments.get(i)?);
+        let angle = |i: usize| parse_angle(arguments.get(i)?);
+
+        let name = name.trim().to_ascii_lowercase();
+        let function = match (name.as_str(), arguments.len()) {
+            ("translate", 1) => TransformFunction::Translate(length(0)?, Length::ZERO),
+            ("translate", 2) => TransformFunction::Translate(length(0)?, length(1)?),
+            ("translatex", 1) => TransformFunction::Translate(length(0)?, Length::ZERO),
+            ("translatey", 1) => TransformFunction::Translate(Length::ZERO, length(0)?),
+            ("rotate", 1) => TransformFunction::Rotate(angle(0)?),
+            ("scale", 1) => TransformFunction::Scale(number(0)?, number(0)?),
+            ("scale", 2) => TransformFunction::Scale(number(0)?, number(1)?),
+            ("scalex", 1) => TransformFunction::Scale(number(0)?, 1.0),
+            ("scaley", 1) => TransformFunction::Scale(1.0, number(0)?),
+            ("skew", 1) => TransformFunction::Skew(angle(0)?, 0.0),
+            ("skew", 2) => TransformFunction::Skew(angle(0)?, angle(1)?),
+            ("skewx", 1) => TransformFunction::Skew(angle(0)?, 0.0),
+            ("skewy", 1) => TransformFunction::Skew(0.0, angle(0)?),
+            ("matrix", 6) => {
+                let matrix = Matrix {
+                    a: number(0)?,
+                    b: number(1)?,
+                    c: number(2)?,
+                    d: number(3)?,
+                    e: Length::px(number(4)?),
+                    f: Length::px(number(5)?),
+                };
+                result.functions.extend(matrix.decompose());
+                continue;
+            }
+            _ => return None,
+        };
+        result.functions.push(function);
+    }
+    Some(result)
+}
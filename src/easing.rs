@@ -8,17 +8,17 @@ const C4: f64 = (2.0 * PI) / 3.0;
 const C5: f64 = (2.0 * PI) / 4.5;
 
 /// <https://easings.net/#easeInBack>
-pub const BACK_IN: Easing = |t: f64| -> f64 {
+pub const BACK_IN: fn(f64) -> f64 = |t: f64| -> f64 {
     C3 * t * t * t - C1 * t * t
 };
 
 /// <https://easings.net/#easeOutBack>
-pub const BACK_OUT: Easing = |t: f64| -> f64 {
+pub const BACK_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
 };
 
 /// <https://easings.net/#easeInOutBack>
-pub const BACK_IN_OUT: Easing = |t: f64| -> f64 {
+pub const BACK_IN_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if t < 0.5 {
         ((2.0 * t).powi(2) * ((C2 + 1.0) * 2.0 * t - C2)) / 2.0
     } else {
@@ -27,27 +27,27 @@ pub const BACK_IN_OUT: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeInBounce>
-pub const BOUNCE_IN: Easing = |t: f64| -> f64 {
+pub const BOUNCE_IN: fn(f64) -> f64 = |t: f64| -> f64 {
     1.0 - BOUNCE_OUT(1.0 - t)
 };
 
 /// <https://easings.net/#easeOutBounce>
-pub const BOUNCE_OUT: Easing = |t: f64| -> f64 {
+pub const BOUNCE_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     const N1: f64 = 7.5625;
     const D1: f64 = 2.75;
     if t < 1.0 / D1 {
-        return N1 * t * t;
+        N1 * t * t
     } else if t < 2.0 / D1 {
-        return N1 * (t - 1.5 / D1).powi(2) + 0.75;
+        N1 * (t - 1.5 / D1).powi(2) + 0.75
     } else if t < 2.5 / D1 {
-        return N1 * (t - 2.25 / D1).powi(2) + 0.9375;
+        N1 * (t - 2.25 / D1).powi(2) + 0.9375
     } else {
-        return N1 * (t - 2.625 / D1).powi(2) + 0.984375;
+        N1 * (t - 2.625 / D1).powi(2) + 0.984375
     }
 };
 
 /// <https://easings.net/#easeInOutBounce>
-pub const BOUNCE_IN_OUT: Easing = |t: f64| -> f64 {
+pub const BOUNCE_IN_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if t < 0.5 {
         (1.0 - BOUNCE_OUT(1.0 - 2.0 * t)) / 2.0
     } else {
@@ -56,17 +56,17 @@ pub const BOUNCE_IN_OUT: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeInCirc>
-pub const CIRC_IN: Easing = |t: f64| -> f64 {
+pub const CIRC_IN: fn(f64) -> f64 = |t: f64| -> f64 {
     1.0 - (1.0 - t.powi(2)).sqrt()
 };
 
 /// <https://easings.net/#easeOutCirc>
-pub const CIRC_OUT: Easing = |t: f64| -> f64 {
+pub const CIRC_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     (1.0 - (t - 1.0).powi(2)).sqrt()
 };
 
 /// <https://easings.net/#easeInOutCirc>
-pub const CIRC_IN_OUT: Easing = |t: f64| -> f64 {
+pub const CIRC_IN_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if t < 0.5 {
         (1.0 - (1.0 - (2.0 * t).powi(2)).sqrt()) / 2.0
     } else {
@@ -75,17 +75,17 @@ pub const CIRC_IN_OUT: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeInCubic>
-pub const CUBIC_IN: Easing = |t: f64| -> f64 {
+pub const CUBIC_IN: fn(f64) -> f64 = |t: f64| -> f64 {
     t * t * t
 };
 
 /// <https://easings.net/#easeOutCubic>
-pub const CUBIC_OUT: Easing = |t: f64| -> f64 {
+pub const CUBIC_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     1.0 - (1.0 - t).powi(3)
 };
 
 /// <https://easings.net/#easeInOutCubic>
-pub const CUBIC_IN_OUT: Easing = |t: f64| -> f64 {
+pub const CUBIC_IN_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if t < 0.5 {
         4.0 * t * t * t
     } else {
@@ -94,7 +94,7 @@ pub const CUBIC_IN_OUT: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeInElastic>
-pub const ELASTIC_IN: Easing = |t: f64| -> f64 {
+pub const ELASTIC_IN: fn(f64) -> f64 = |t: f64| -> f64 {
     if t <= 0.0 {
         0.0
     } else if 1.0 <= t {
@@ -105,7 +105,7 @@ pub const ELASTIC_IN: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeOutElastic>
-pub const ELASTIC_OUT: Easing = |t: f64| -> f64 {
+pub const ELASTIC_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if t <= 0.0 {
         0.0
     } else if 1.0 <= t {
@@ -116,7 +116,7 @@ pub const ELASTIC_OUT: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeInOutElastic>
-pub const ELASTIC_IN_OUT: Easing = |t: f64| -> f64 {
+pub const ELASTIC_IN_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if t <= 0.0 {
         0.0
     } else if 1.0 <= t {
@@ -129,7 +129,7 @@ pub const ELASTIC_IN_OUT: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeInExpo>
-pub const EXPO_IN: Easing = |t: f64| -> f64 {
+pub const EXPO_IN: fn(f64) -> f64 = |t: f64| -> f64 {
     if t <= 0.0 {
         0.0
     } else {
@@ -138,7 +138,7 @@ pub const EXPO_IN: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeOutExpo>
-pub const EXPO_OUT: Easing = |t: f64| -> f64 {
+pub const EXPO_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if 1.0 <= t {
         1.0
     } else {
@@ -147,7 +147,7 @@ pub const EXPO_OUT: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeInOutExpo>
-pub const EXPO_IN_OUT: Easing = |t: f64| -> f64 {
+pub const EXPO_IN_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if t <= 0.0 {
         0.0
     } else if 1.0 <= t {
@@ -159,27 +159,27 @@ pub const EXPO_IN_OUT: Easing = |t: f64| -> f64 {
     }
 };
 
-pub const LINEAR: Easing = |t: f64| -> f64 {
+pub const LINEAR: fn(f64) -> f64 = |t: f64| -> f64 {
     t
 };
 
 /// A linear easing that goes from `1.0` to `0.0`.
-pub const REVERSE: Easing = |t: f64| -> f64 {
+pub const REVERSE: fn(f64) -> f64 = |t: f64| -> f64 {
     1.0 - t
 };
 
 /// <https://easings.net/#easeInQuad>
-pub const QUAD_IN: Easing = |t: f64| -> f64 {
+pub const QUAD_IN: fn(f64) -> f64 = |t: f64| -> f64 {
     t * t
 };
 
 /// <https://easings.net/#easeOutQuad>
-pub const QUAD_OUT: Easing = |t: f64| -> f64 {
+pub const QUAD_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     1.0 - (1.0 - t).powi(2)
 };
 
 /// <https://easings.net/#easeInOutQuad>
-pub const QUAD_IN_OUT: Easing = |t: f64| -> f64 {
+pub const QUAD_IN_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if t < 0.5 {
         2.0 * t * t
     } else {
@@ -188,17 +188,17 @@ pub const QUAD_IN_OUT: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeInQuart>
-pub const QUART_IN: Easing = |t: f64| -> f64 {
+pub const QUART_IN: fn(f64) -> f64 = |t: f64| -> f64 {
     t * t * t * t
 };
 
 /// <https://easings.net/#easeOutQuart>
-pub const QUART_OUT: Easing = |t: f64| -> f64 {
+pub const QUART_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     1.0 - (1.0 - t).powi(4)
 };
 
 /// <https://easings.net/#easeInOutQuart>
-pub const QUART_IN_OUT: Easing = |t: f64| -> f64 {
+pub const QUART_IN_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if t < 0.5 {
         8.0 * t * t * t * t
     } else {
@@ -207,17 +207,17 @@ pub const QUART_IN_OUT: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeInQuint>
-pub const QUINT_IN: Easing = |t: f64| -> f64 {
+pub const QUINT_IN: fn(f64) -> f64 = |t: f64| -> f64 {
     t * t * t * t * t
 };
 
 /// <https://easings.net/#easeOutQuint>
-pub const QUINT_OUT: Easing = |t: f64| -> f64 {
+pub const QUINT_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     1.0 - (1.0 - t).powi(5)
 };
 
 /// <https://easings.net/#easeInOutQuint>
-pub const QUINT_IN_OUT: Easing = |t: f64| -> f64 {
+pub const QUINT_IN_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     if t < 0.5 {
         16.0 * t * t * t * t * t
     } else {
@@ -226,16 +226,232 @@ pub const QUINT_IN_OUT: Easing = |t: f64| -> f64 {
 };
 
 /// <https://easings.net/#easeInSine>
-pub const SINE_IN: Easing = |t: f64| -> f64 {
+pub const SINE_IN: fn(f64) -> f64 = |t: f64| -> f64 {
     1.0 - (t * PI / 2.0).cos()
 };
 
 /// <https://easings.net/#easeOutSine>
-pub const SINE_OUT: Easing = |t: f64| -> f64 {
+pub const SINE_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     (t * PI / 2.0).sin()
 };
 
 /// <https://easings.net/#easeInOutSine>
-pub const SINE_IN_OUT: Easing = |t: f64| -> f64 {
+pub const SINE_IN_OUT: fn(f64) -> f64 = |t: f64| -> f64 {
     -((PI * t).cos() - 1.0) / 2.0
 };
+
+/// Where the jumps of a [`steps()`] easing happen, see
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/easing-function/steps#step-position>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StepPosition {
+    /// The first jump happens right at the start of the animation. Same as `start` in CSS.
+    JumpStart,
+
+    /// The last jump happens right at the end of the animation. Same as `end` in CSS.
+    JumpEnd,
+
+    /// No jump at the start or the end, the animation holds at the start and the end value.
+    JumpNone,
+
+    /// Jumps both at the start and the end of the animation.
+    JumpBoth,
+}
+
+/// CSS `ease`, the same as `cubic-bezier(0.25, 0.1, 0.25, 1.0)`
+pub const EASE: Easing = cubic_bezier(0.25, 0.1, 0.25, 1.0);
+
+/// CSS `ease-in`, the same as `cubic-bezier(0.42, 0.0, 1.0, 1.0)`
+pub const EASE_IN: Easing = cubic_bezier(0.42, 0.0, 1.0, 1.0);
+
+/// CSS `ease-out`, the same as `cubic-bezier(0.0, 0.0, 0.58, 1.0)`
+pub const EASE_OUT: Easing = cubic_bezier(0.0, 0.0, 0.58, 1.0);
+
+/// CSS `ease-in-out`, the same as `cubic-bezier(0.42, 0.0, 0.58, 1.0)`
+pub const EASE_IN_OUT: Easing = cubic_bezier(0.42, 0.0, 0.58, 1.0);
+
+/// CSS `step-start`, the same as `steps(1, jump-start)`
+pub const STEP_START: Easing = steps(1, StepPosition::JumpStart);
+
+/// CSS `step-end`, the same as `steps(1, jump-end)`
+pub const STEP_END: Easing = steps(1, StepPosition::JumpEnd);
+
+/// An easing that follows a cubic Bézier curve, the same as the CSS `cubic-bezier(x1, y1, x2, y2)` function.
+/// The `x` coordinates of the control points are clamped between 0.0 and 1.0.
+///
+/// ```
+/// # use leptos_animation::easing;
+/// let ease = easing::cubic_bezier(0.25, 0.1, 0.25, 1.0);
+/// assert!((ease.apply(0.5) - 0.8024033877399112).abs() < 1e-6);
+/// assert_eq!(ease.apply(0.0), 0.0);
+/// assert_eq!(ease.apply(1.0), 1.0);
+/// ```
+///
+/// The other CSS keywords match the values of the curves in the specification:
+/// ```
+/// # use leptos_animation::easing;
+/// let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+/// assert!(close(easing::EASE_IN.apply(0.25), 0.09346465071882486));
+/// assert!(close(easing::EASE_IN.apply(0.75), 0.6218618691748902));
+/// assert!(close(easing::EASE_OUT.apply(0.25), 0.37813813082510983));
+/// assert!(close(easing::EASE_OUT.apply(0.75), 0.9065353492811752));
+/// assert!(close(easing::EASE_IN_OUT.apply(0.25), 0.12916193104731982));
+/// assert!(close(easing::EASE_IN_OUT.apply(0.5), 0.5));
+/// assert!(close(easing::EASE_IN_OUT.apply(0.75), 0.8708380689526802));
+/// ```
+///
+/// The `y` coordinates are not clamped, so a curve can overshoot before it settles:
+/// ```
+/// # use leptos_animation::easing;
+/// let back = easing::cubic_bezier(0.68, -0.55, 0.265, 1.55);
+/// assert!((back.apply(0.25) - -0.08280710882832258).abs() < 1e-6);
+/// assert!((back.apply(0.75) - 1.0891657748134211).abs() < 1e-6);
+/// assert_eq!(back.apply(1.0), 1.0);
+/// ```
+///
+/// Curves that are almost vertical halfway are evaluated accurately as well:
+/// ```
+/// # use leptos_animation::easing;
+/// let steep = easing::cubic_bezier(1.0, 0.0, 0.0, 1.0);
+/// assert!((steep.apply(0.45) - 0.1768808374790416).abs() < 1e-6);
+/// assert!((steep.apply(0.49) - 0.301418678755382).abs() < 1e-6);
+/// ```
+pub const fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64) -> Easing {
+    Easing::CubicBezier {
+        x1: x1.clamp(0.0, 1.0),
+        y1,
+        x2: x2.clamp(0.0, 1.0),
+        y2,
+    }
+}
+
+/// An easing that jumps between a number of equal steps, the same as the CSS `steps(steps, position)` function.
+/// There is always at least one step, or two steps for [`StepPosition::JumpNone`].
+///
+/// ```
+/// # use leptos_animation::easing::{self, StepPosition};
+/// let jump_end = easing::steps(4, StepPosition::JumpEnd);
+/// assert_eq!(jump_end.apply(0.0), 0.0);
+/// assert_eq!(jump_end.apply(0.3), 0.25);
+/// assert_eq!(jump_end.apply(1.0), 1.0);
+///
+/// let jump_start = easing::steps(4, StepPosition::JumpStart);
+/// assert_eq!(jump_start.apply(0.0), 0.25);
+/// assert_eq!(jump_start.apply(0.3), 0.5);
+///
+/// let jump_none = easing::steps(5, StepPosition::JumpNone);
+/// assert_eq!(jump_none.apply(0.1), 0.0);
+/// assert_eq!(jump_none.apply(0.5), 0.5);
+/// assert_eq!(jump_none.apply(0.9), 1.0);
+///
+/// let jump_both = easing::steps(3, StepPosition::JumpBoth);
+/// assert_eq!(jump_both.apply(0.0), 0.25);
+/// assert_eq!(jump_both.apply(0.5), 0.5);
+/// assert_eq!(jump_both.apply(1.0), 1.0);
+/// ```
+pub const fn steps(steps: u32, position: StepPosition) -> Easing {
+    let min_steps = match position {
+        StepPosition::JumpNone => 2,
+        _ => 1,
+    };
+    Easing::Steps {
+        steps: if steps < min_steps { min_steps } else { steps },
+        position,
+    }
+}
+
+/// Evaluates a cubic Bézier easing curve at `t`, following <https://www.w3.org/TR/css-easing-1/#cubic-bezier-easing-functions>.
+/// Outside the 0.0 - 1.0 range the curve is extended in a straight line along its tangent, the end
+/// points themselves are exact.
+pub(crate) fn evaluate_cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, t: f64) -> f64 {
+    if t <= 0.0 {
+        let gradient = if x1 > 0.0 {
+            y1 / x1
+        } else if y1 == 0.0 && x2 > 0.0 {
+            y2 / x2
+        } else {
+            0.0
+        };
+        return gradient * t;
+    }
+    if t >= 1.0 {
+        let gradient = if x2 < 1.0 {
+            (y2 - 1.0) / (x2 - 1.0)
+        } else if y2 == 1.0 && x1 < 1.0 {
+            (y1 - 1.0) / (x1 - 1.0)
+        } else {
+            0.0
+        };
+        return 1.0 + gradient * (t - 1.0);
+    }
+
+    // Polynomial coefficients of the curve, with the start at (0, 0) and the end at (1, 1)
+    let cx = 3.0 * x1;
+    let bx = 3.0 * (x2 - x1) - cx;
+    let ax = 1.0 - cx - bx;
+    let cy = 3.0 * y1;
+    let by = 3.0 * (y2 - y1) - cy;
+    let ay = 1.0 - cy - by;
+
+    let sample_x = |s: f64| ((ax * s + bx) * s + cx) * s;
+    let sample_y = |s: f64| ((ay * s + by) * s + cy) * s;
+    let sample_dx = |s: f64| (3.0 * ax * s + 2.0 * bx) * s + cx;
+
+    const EPSILON: f64 = 1e-7;
+
+    // Find the curve parameter for x = t, first with Newton's method
+    let mut s = t;
+    for _ in 0..8 {
+        let x = sample_x(s) - t;
+        if x.abs() < EPSILON {
+            return sample_y(s);
+        }
+        let dx = sample_dx(s);
+        if dx.abs() < EPSILON {
+            break;
+        }
+        s -= x / dx;
+    }
+
+    // Fall back to bisection, which always converges
+    let (mut low, mut high) = (0.0, 1.0);
+    s = t;
+    while low < high {
+        let x = sample_x(s);
+        if (x - t).abs() < EPSILON {
+            break;
+        }
+        if t > x {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (high - low) / 2.0 + low;
+        if high - low < EPSILON {
+            break;
+        }
+    }
+    sample_y(s)
+}
+
+/// Evaluates a steps easing at `t`, following <https://www.w3.org/TR/css-easing-1/#step-easing-algo>
+pub(crate) fn evaluate_steps(steps: u32, position: StepPosition, t: f64) -> f64 {
+    let steps = steps as f64;
+    let mut step = (t * steps).floor();
+    if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+        step += 1.0;
+    }
+
+    let jumps = match position {
+        StepPosition::JumpStart | StepPosition::JumpEnd => steps,
+        StepPosition::JumpNone => steps - 1.0,
+        StepPosition::JumpBoth => steps + 1.0,
+    };
+
+    if t >= 0.0 && step < 0.0 {
+        step = 0.0;
+    }
+    if t <= 1.0 && step > jumps {
+        step = jumps;
+    }
+    step / jumps
+}
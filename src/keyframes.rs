@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use crate::{easing::LINEAR, AnimationTarget, Easing};
+
+/// A `Keyframe` is an intermediate stop that an animation passes through on its way to the target
+/// value, similar to a step in a CSS `@keyframes` rule.
+///
+/// Keyframes are set on the [`keyframes`](crate::AnimationTarget::keyframes) field of an
+/// [`AnimationTarget`]. All keyframes are played within the `duration` of the animation:
+/// * The animation starts at the current value at offset 0.0 and ends at the `target` at offset 1.0.
+/// * The `easing` of a keyframe is applied to the segment that leads up to that keyframe.
+/// * The `easing` of the `AnimationTarget` is applied to the last segment, from the last keyframe
+///   towards the `target`.
+///
+/// A keyframe at offset 0.0 makes the animation start from that value instead of the current value.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animated_signal, easing, keyframes::Keyframe, tween_default, AnimationContext, AnimationTarget};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// let (value, set_value) = create_signal(0.0);
+///
+/// // Overshoot to 150% of the target halfway through the animation
+/// let animated_value = create_animated_signal(
+///     move || {
+///         let target = value.get();
+///         AnimationTarget::new(target)
+///             .duration(Duration::from_secs(1))
+///             .easing(easing::LINEAR)
+///             .keyframes(vec![Keyframe::new(0.5, target * 1.5, easing::LINEAR)])
+///     },
+///     tween_default::<f64, f64>,
+/// );
+///
+/// set_value.set(100.0);
+/// clock.advance(Duration::from_millis(250));
+/// animation_frame();
+/// assert_eq!(animated_value.get_untracked(), 75.0);
+///
+/// clock.advance(Duration::from_millis(250));
+/// animation_frame();
+/// assert_eq!(animated_value.get_untracked(), 150.0);
+///
+/// clock.advance(Duration::from_millis(250));
+/// animation_frame();
+/// assert_eq!(animated_value.get_untracked(), 125.0);
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keyframe<T> {
+    /// The moment in the animation at which this value is reached, between 0.0 and 1.0. Offsets
+    /// outside of that range are clamped and keyframes with a NaN offset are skipped.
+    pub offset: f64,
+
+    /// The value at this keyframe
+    pub value: T,
+
+    /// The easing method to apply to the segment leading up to this keyframe
+    pub easing: Easing,
+}
+
+impl<T> Keyframe<T> {
+    /// Create a keyframe, an offset outside of 0.0 to 1.0 is moved to the nearest end
+    pub fn new(offset: f64, value: T, easing: impl Into<Easing>) -> Keyframe<T> {
+        Keyframe {
+            offset: offset.clamp(0.0, 1.0),
+            value,
+            easing: easing.into(),
+        }
+    }
+}
+
+impl<T> From<(f64, T)> for Keyframe<T> {
+    fn from((offset, value): (f64, T)) -> Self {
+        Keyframe::new(offset, value, LINEAR)
+    }
+}
+
+impl<T, E: Into<Easing>> From<(f64, T, E)> for Keyframe<T> {
+    fn from((offset, value, easing): (f64, T, E)) -> Self {
+        Keyframe::new(offset, value, easing)
+    }
+}
+
+impl<T> From<(T, Vec<Keyframe<T>>)> for AnimationTarget<T> {
+    fn from((target, keyframes): (T, Vec<Keyframe<T>>)) -> Self {
+        AnimationTarget::new(target).keyframes(keyframes)
+    }
+}
+
+impl<T> From<(T, Duration, Vec<Keyframe<T>>)> for AnimationTarget<T> {
+    fn from((target, duration, keyframes): (T, Duration, Vec<Keyframe<T>>)) -> Self {
+        AnimationTarget::new(target)
+            .duration(duration)
+            .keyframes(keyframes)
+    }
+}
+
+/// The keyframes with their offsets clamped between 0.0 and 1.0. A keyframe with a NaN offset has no
+/// place in the animation and is dropped.
+pub(crate) fn valid_keyframes<T>(
+    keyframes: impl IntoIterator<Item = Keyframe<T>>,
+) -> Vec<Keyframe<T>> {
+    keyframes
+        .into_iter()
+        .filter(|keyframe| !keyframe.offset.is_nan())
+        .map(|keyframe| Keyframe {
+            offset: keyframe.offset.clamp(0.0, 1.0),
+            ..keyframe
+        })
+        .collect()
+}
+
+/// The segment between two keyframes at the given position and the eased progress within it.
+/// The first segment starts at `from` and the last segment ends at `to` with the given easing.
+pub(crate) fn keyframe_segment<'a, T>(
+    from: &'a T,
+    keyframes: &'a [Keyframe<T>],
+    to: &'a T,
+    easing: &Easing,
+    position: f64,
+) -> (&'a T, &'a T, f64) {
+    let mut segment_start = (0.0, from);
+    for keyframe in keyframes {
+        if position < keyframe.offset {
+            return segment(
+                segment_start,
+                (keyframe.offset, &keyframe.value),
+                &keyframe.easing,
+                position,
+            );
+        }
+        segment_start = (keyframe.offset, &keyframe.value);
+    }
+    segment(segment_start, (1.0, to), easing, position)
+}
+
+fn segment<'a, T>(
+    (start_offset, start): (f64, &'a T),
+    (end_offset, end): (f64, &'a T),
+    easing: &Easing,
+    position: f64,
+) -> (&'a T, &'a T, f64) {
+    let progress = if end_offset > start_offset {
+        (position - start_offset) / (end_offset - start_offset)
+    } else {
+        1.0
+    };
+    (start, end, easing.apply(progress))
+}
@@ -1,20 +1,48 @@
 use instant::Instant;
+use std::cell::RefCell;
 use std::cmp::PartialEq;
-use std::fmt::Debug;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
 use std::ops::{Add, Deref, Mul};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
 use std::{collections::VecDeque, ops::Sub, time::Duration};
 
 use leptos::{
-    create_effect, create_memo, create_trigger, leptos_dom::helpers::AnimationFrameRequestHandle,
-    on_cleanup, provide_context, request_animation_frame_with_handle, store_value, use_context,
-    Effect, IntoView, Memo, Signal, SignalDispose, SignalGet, SignalGetUntracked, SignalWith,
-    StoredValue, Trigger, View,
+    create_isomorphic_effect, create_memo, create_rw_signal, create_trigger,
+    leptos_dom::helpers::AnimationFrameRequestHandle, on_cleanup, provide_context,
+    request_animation_frame_with_handle, store_value, use_context, Effect, IntoView, Memo,
+    RwSignal, Signal, SignalDispose, SignalGet, SignalGetUntracked, SignalSet, SignalUpdate as _,
+    SignalWith, StoredValue, Trigger, View,
 };
 
+pub mod animatable;
 pub mod animation_target;
+pub mod clock;
+#[cfg(feature = "color")]
+pub mod color;
+pub mod css;
 pub mod easing;
+pub mod keyframes;
+pub mod spring;
+pub mod text;
+pub mod timeline;
+
+pub use animatable::Animatable;
+pub use animation_target::AnimationDefaults;
+use clock::{Clock, ManualClock, SystemClock};
+use keyframes::{keyframe_segment, valid_keyframes, Keyframe};
+use spring::Spring;
+
+/// Derive [`Animatable`] for structs and enums, see the `leptos_animation_derive` crate for details.
+/// Requires the `derive` feature.
+#[cfg(feature = "derive")]
+pub use leptos_animation_derive::Animate;
 
 #[derive(Clone)]
+#[allow(clippy::enum_variant_names)]
 enum AnimationContextState {
     NoAnimationFrameRequested,
     AnimationFrameRequested(AnimationFrameRequestHandle),
@@ -38,8 +66,61 @@ pub struct AnimationContext {
     pub animation_frame: Trigger,
     state: StoredValue<AnimationContextState>,
     custom_request_animation_frame: StoredValue<Option<Box<dyn Fn()>>>,
+    clock: StoredValue<Rc<dyn Clock>>,
+    active_count: RwSignal<usize>,
+    /// The motion policy of the system, or the one set by hand
+    system_motion_policy: RwSignal<MotionPolicy>,
+    motion_policy_override: RwSignal<Option<MotionPolicy>>,
+    /// Set once the animations are driven by [`AnimationContext::step()`]
+    step_clock: StoredValue<Option<ManualClock>>,
+    visibility_source: RwSignal<Signal<bool>>,
+    visibility_policy: StoredValue<VisibilityPolicy>,
+    /// The time at which the page was hidden, while the animation clock is paused
+    hidden_since: StoredValue<Option<Instant>>,
+    /// The total time the animation clock has been paused for
+    hidden_for: StoredValue<Duration>,
+    /// Set while all running animations are finished at once
+    finishing: StoredValue<bool>,
+    min_frame_interval: StoredValue<Option<Duration>>,
+    last_frame: StoredValue<Option<Instant>>,
+    frame_hooks: StoredValue<Vec<FrameHook>>,
+    /// Whether animated signals render their target instead of animating, see [`AnimationContext::is_server()`]
+    server: StoredValue<bool>,
 }
 
+/// Animation frames that arrive this much earlier than the minimum frame interval are not skipped,
+/// to allow for some jitter in the timing of animation frames
+const FRAME_INTERVAL_TOLERANCE: Duration = Duration::from_millis(1);
+
+/// What happens to running animations while the page is hidden, see [`AnimationContext::set_visibility_policy()`]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum VisibilityPolicy {
+    /// Stand still while the page is hidden and continue where they were once it is visible again
+    #[default]
+    Pause,
+
+    /// Jump to the end as soon as the page is hidden
+    Finish,
+}
+
+/// How much motion animations are allowed to have, see [`AnimationContext::motion_policy()`]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MotionPolicy {
+    /// Animations play as they are configured
+    #[default]
+    Full,
+
+    /// Animations are turned into a short linear transition without keyframes, springs or repetitions.
+    /// Durations are capped at [`REDUCED_MOTION_DURATION`].
+    Reduced,
+
+    /// Every new target behaves like [`AnimationMode::Snap`] and running animations jump to their end
+    None,
+}
+
+/// The longest an animation plays with [`MotionPolicy::Reduced`]
+pub const REDUCED_MOTION_DURATION: Duration = Duration::from_millis(150);
+
 impl AnimationContext {
     /// Sets up an AnimationContext for this scope and all child scopes. For normal use you only
     /// need to call this once in a root component of the application.
@@ -51,9 +132,37 @@ impl AnimationContext {
             animation_frame,
             state,
             custom_request_animation_frame: store_value(None),
+            clock: store_value(Rc::new(SystemClock)),
+            active_count: create_rw_signal(0),
+            system_motion_policy: create_rw_signal(MotionPolicy::Full),
+            motion_policy_override: create_rw_signal(None),
+            step_clock: store_value(None),
+            visibility_source: create_rw_signal(Signal::derive(|| true)),
+            visibility_policy: store_value(VisibilityPolicy::default()),
+            hidden_since: store_value(None),
+            hidden_for: store_value(Duration::ZERO),
+            finishing: store_value(false),
+            min_frame_interval: store_value(None),
+            last_frame: store_value(None),
+            frame_hooks: store_value(Vec::new()),
+            server: store_value(cfg!(feature = "ssr")),
         };
         provide_context(animation_context);
 
+        #[cfg(target_arch = "wasm32")]
+        {
+            follow_prefers_reduced_motion(animation_context.system_motion_policy);
+            animation_context.set_visibility_source(page_visibility());
+        }
+
+        create_isomorphic_effect(move |was_visible| {
+            let visible = animation_context.visibility_source.get().get();
+            if was_visible.is_some_and(|was_visible| was_visible != visible) {
+                animation_context.visibility_changed(visible);
+            }
+            visible
+        });
+
         on_cleanup(move || {
             if let AnimationContextState::AnimationFrameRequested(handle) = state.get_value() {
                 handle.cancel()
@@ -63,6 +172,14 @@ impl AnimationContext {
         animation_context
     }
 
+    /// Sets up an AnimationContext like [`provide()`](AnimationContext::provide) with application wide
+    /// defaults for animation options, see [`AnimationDefaults`]
+    pub fn provide_with_defaults(defaults: AnimationDefaults) -> AnimationContext {
+        let animation_context = Self::provide();
+        defaults.provide();
+        animation_context
+    }
+
     /// This method can be used instead of `provide` when you are in a non-web environment such as
     /// a desktop application. *For web environments it is recommended to use the normal `provide` instead*
     ///
@@ -105,7 +222,6 @@ impl AnimationContext {
     /// });
     ///
     /// ````
-
     pub fn provide_with_custom_request_animation_frame(
         callback: impl Fn() + 'static,
     ) -> (AnimationContext, impl Fn()) {
@@ -113,62 +229,560 @@ impl AnimationContext {
         animation_context
             .custom_request_animation_frame
             .set_value(Some(Box::new(callback)));
+        animation_context.server.set_value(false);
 
         (animation_context, move || {
             if !matches!(
                 animation_context.state.get_value(),
                 AnimationContextState::NoAnimationFrameRequested
             ) {
-                animation_context
-                    .state
-                    .set_value(AnimationContextState::NoAnimationFrameRequested);
-                animation_context.animation_frame.notify();
+                animation_context.animation_frame_arrived();
             }
         })
     }
 
+    /// Sets up an AnimationContext that is only driven by calls to [`step()`](AnimationContext::step),
+    /// for example from a game loop, a test or when rendering animation frames offline.
+    /// It does not rely on the system time or `window.request_animation_frame()`.
+    pub fn provide_headless() -> AnimationContext {
+        let animation_context = Self::provide();
+        animation_context.start_stepping();
+        animation_context
+    }
+
+    /// Advance all animations in this context by `dt` and update the animated signals. Returns
+    /// whether any animation is still running and more steps are needed.
+    ///
+    /// The first call takes over the animation loop: from then on the time only moves forward by
+    /// calling `step()` and no animation frames are requested from the browser anymore.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{create_animation, easing, AnimationContext};
+    /// # let runtime = create_runtime();
+    /// let context = AnimationContext::provide_headless();
+    /// let (target, set_target) = create_signal(0.0);
+    /// let value = create_animation::<f64>(move || (target.get(), Duration::from_secs(1), easing::LINEAR).into());
+    ///
+    /// // Render the animation at 10 frames per second
+    /// set_target.set(100.0);
+    /// let mut frames = vec![];
+    /// while context.step(Duration::from_millis(100)) {
+    ///     frames.push(value.get_untracked());
+    /// }
+    /// frames.push(value.get_untracked());
+    ///
+    /// assert_eq!(frames.len(), 10);
+    /// assert_eq!(frames[4], 50.0);
+    /// assert_eq!(frames[9], 100.0);
+    /// # runtime.dispose();
+    /// ```
+    pub fn step(&self, dt: Duration) -> bool {
+        let clock = match self.step_clock.get_value() {
+            Some(clock) => clock,
+            None => self.start_stepping(),
+        };
+        clock.advance(dt);
+
+        self.state
+            .set_value(AnimationContextState::NoAnimationFrameRequested);
+        self.update();
+
+        !matches!(
+            self.state.get_value(),
+            AnimationContextState::NoAnimationFrameRequested
+        )
+    }
+
+    /// Switch over to a manual clock and record animation frame requests instead of passing them on
+    fn start_stepping(&self) -> ManualClock {
+        let clock = ManualClock::starting_at(self.clock_now());
+        self.set_clock(clock.clone());
+        self.step_clock.set_value(Some(clock.clone()));
+        self.custom_request_animation_frame
+            .set_value(Some(Box::new(|| {})));
+        self.server.set_value(false);
+        if let AnimationContextState::AnimationFrameRequested(handle) = self.state.get_value() {
+            handle.cancel();
+            self.state
+                .set_value(AnimationContextState::CustomAnimationFrameRequested);
+        }
+        clock
+    }
+
+    /// Replace the clock that is used to determine the progress of all animations in this context.
+    /// See [`ManualClock`](clock::ManualClock) for a clock that can be controlled in tests.
+    pub fn set_clock(&self, clock: impl Clock + 'static) {
+        self.clock.set_value(Rc::new(clock));
+    }
+
+    /// The current time according to the clock of this context. This time stands still while the page
+    /// is hidden, see [`VisibilityPolicy::Pause`].
+    pub fn now(&self) -> Instant {
+        let now = self
+            .hidden_since
+            .get_value()
+            .unwrap_or_else(|| self.clock_now());
+        let hidden_for = self.hidden_for.get_value();
+        now.checked_sub(hidden_for).unwrap_or(now)
+    }
+
+    /// The current time of the clock, including the time the page has been hidden
+    fn clock_now(&self) -> Instant {
+        self.clock.with_value(|clock| clock.now())
+    }
+
+    /// Set what happens to running animations while the page is hidden. Defaults to [`VisibilityPolicy::Pause`].
+    ///
+    /// Browsers stop calling `window.request_animation_frame()` callbacks for hidden pages, without
+    /// a policy running animations would jump to their end when the page becomes visible again.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animation, easing, AnimationContext, VisibilityPolicy};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (visible, set_visible) = create_signal(true);
+    /// context.set_visibility_source(visible);
+    ///
+    /// let (target, set_target) = create_signal(0.0);
+    /// let value = create_animation::<f64>(move || (target.get(), Duration::from_secs(1), easing::LINEAR).into());
+    /// set_target.set(100.0);
+    /// clock.advance(Duration::from_millis(250));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 25.0);
+    ///
+    /// // The animation stands still while the page is hidden
+    /// set_visible.set(false);
+    /// clock.advance(Duration::from_secs(10));
+    /// set_visible.set(true);
+    /// clock.advance(Duration::from_millis(250));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 50.0);
+    ///
+    /// // Or finishes as soon as the page is hidden
+    /// context.set_visibility_policy(VisibilityPolicy::Finish);
+    /// set_visible.set(false);
+    /// assert_eq!(value.get_untracked(), 100.0);
+    /// assert!(!value.is_running().get_untracked());
+    /// # runtime.dispose();
+    /// ```
+    pub fn set_visibility_policy(&self, visibility_policy: VisibilityPolicy) {
+        self.visibility_policy.set_value(visibility_policy);
+    }
+
+    /// Replace the signal that tells whether the page is visible. In the browser this follows the
+    /// Page Visibility API by default, elsewhere the page is always visible. Useful for tests or
+    /// for environments with their own notion of visibility, such as a minimized window.
+    pub fn set_visibility_source(&self, visible: impl Into<Signal<bool>>) {
+        self.visibility_source.set(visible.into());
+    }
+
+    fn visibility_changed(&self, visible: bool) {
+        if visible {
+            if let Some(hidden_since) = self.hidden_since.get_value() {
+                let hidden_for = self.clock_now().saturating_duration_since(hidden_since);
+                self.hidden_for.update_value(|total| *total += hidden_for);
+                self.hidden_since.set_value(None);
+            }
+            self.request_animation_frame();
+            return;
+        }
+
+        match self.visibility_policy.get_value() {
+            VisibilityPolicy::Pause => self.hidden_since.set_value(Some(self.clock_now())),
+            VisibilityPolicy::Finish => {
+                // Update the animated signals right away, animation frames don't arrive while hidden
+                self.finishing.set_value(true);
+                self.update();
+                self.finishing.set_value(false);
+            }
+        }
+    }
+
+    /// The [`MotionPolicy`] for all animations in this context. In the browser it follows the
+    /// `prefers-reduced-motion` media query unless it is set by hand.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animation, AnimationContext, MotionPolicy};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (target, set_target) = create_signal(0.0);
+    /// let value = create_animation::<f64>(move || target.get().into());
+    ///
+    /// context.set_motion_policy(MotionPolicy::None);
+    /// set_target.set(100.0);
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 100.0);
+    /// assert_eq!(context.motion_policy().get_untracked(), MotionPolicy::None);
+    /// # runtime.dispose();
+    /// ```
+    pub fn motion_policy(&self) -> Signal<MotionPolicy> {
+        let AnimationContext {
+            system_motion_policy,
+            motion_policy_override,
+            ..
+        } = *self;
+        Signal::derive(move || {
+            motion_policy_override
+                .get()
+                .unwrap_or(system_motion_policy.get())
+        })
+    }
+
+    /// The current [`MotionPolicy`] without tracking it. Unlike [`motion_policy()`](AnimationContext::motion_policy)
+    /// this does not create a signal, so it is cheap enough to read on every frame.
+    fn motion_policy_untracked(&self) -> MotionPolicy {
+        self.motion_policy_override
+            .get_untracked()
+            .unwrap_or(self.system_motion_policy.get_untracked())
+    }
+
+    /// Set the [`MotionPolicy`] by hand, for example as a setting in the application or in tests.
+    /// This overrides the `prefers-reduced-motion` media query.
+    pub fn set_motion_policy(&self, motion_policy: MotionPolicy) {
+        self.motion_policy_override.set(Some(motion_policy));
+        self.request_animation_frame();
+    }
+
+    /// Stop overriding the [`MotionPolicy`] and follow the `prefers-reduced-motion` media query again
+    pub fn follow_system_motion_policy(&self) {
+        self.motion_policy_override.set(None);
+        self.request_animation_frame();
+    }
+
+    /// Whether this context renders on the server. Contexts set up with [`provide()`](AnimationContext::provide)
+    /// render on the server when the `ssr` feature is enabled. Contexts whose animation frames are driven by
+    /// hand, with [`provide_with_custom_request_animation_frame()`](AnimationContext::provide_with_custom_request_animation_frame)
+    /// or [`step()`](AnimationContext::step), never do, so they keep animating in a build with the `ssr` feature.
+    ///
+    /// On the server no animations run and animated signals render the target value of their source.
+    /// When hydrating in the browser animated signals start from that same target value, so the
+    /// server rendered HTML matches and animations only start when the source changes after hydration.
+    /// ```
+    /// # #[cfg(feature = "ssr")] {
+    /// # use leptos::*;
+    /// # use leptos_animation::{create_animation, AnimationContext};
+    /// let html = leptos::ssr::render_to_string(|| {
+    ///     let context = AnimationContext::provide();
+    ///     assert!(context.is_server());
+    ///
+    ///     let (level, set_level) = create_signal(0.0);
+    ///     let animated_level = create_animation::<f64>(move || level.get().into());
+    ///
+    ///     // Jumps to the new target without animating
+    ///     set_level.set(0.75);
+    ///     assert_eq!(animated_level.get_untracked(), 0.75);
+    ///
+    ///     view! { <meter value=move || animated_level.get()/> }
+    /// });
+    /// assert!(html.contains("value=\"0.75\""));
+    /// # }
+    /// ```
+    pub fn is_server(&self) -> bool {
+        self.server.get_value()
+    }
+
+    /// A signal with the number of animated signals in this context that are currently running
+    /// an animation
+    pub fn active_count(&self) -> Signal<usize> {
+        self.active_count.into()
+    }
+
     /// Manually request a new animation frame. It will result in a `notify()` on the
     /// `AnimationContext.animation_frame` trigger which updates all running animations
     /// simultaneously. Repeated calls will result in only a single animation frame request.
     ///
     /// Animated signals will call this automatically when they are running, it is not necessary
     /// to call this function unless you are doing something custom.
+    ///
+    /// Panics when the browser refuses the request, see [`try_request_animation_frame()`](AnimationContext::try_request_animation_frame)
+    /// for a version that returns an error instead.
     pub fn request_animation_frame(&self) {
+        if let Err(error) = self.try_request_animation_frame() {
+            panic!("{error}");
+        }
+    }
+
+    /// Like [`request_animation_frame()`](AnimationContext::request_animation_frame) but returns an
+    /// error when the browser refuses the request
+    pub fn try_request_animation_frame(&self) -> Result<(), AnimationError> {
         // Prevent multiple animation frame requests from existing simultaneously
-        if matches!(
-            self.state.get_value(),
-            AnimationContextState::NoAnimationFrameRequested
-        ) {
-            self.custom_request_animation_frame
-                .with_value(
-                    |custom_request_animation_frame| match custom_request_animation_frame {
-                        None => {
-                            let this = self.clone();
-                            self.state
-                                .set_value(AnimationContextState::AnimationFrameRequested(
-                                    request_animation_frame_with_handle(move || {
-                                        this.state.set_value(
-                                            AnimationContextState::NoAnimationFrameRequested,
-                                        );
-                                        this.animation_frame.notify();
-                                    })
-                                    .unwrap(),
-                                ))
-                        }
-                        Some(callback) => {
-                            self.state
-                                .set_value(AnimationContextState::CustomAnimationFrameRequested);
-                            callback()
-                        }
-                    },
-                );
+        if self.is_server()
+            || !matches!(
+                self.state.get_value(),
+                AnimationContextState::NoAnimationFrameRequested
+            )
+        {
+            return Ok(());
         }
+
+        self.custom_request_animation_frame
+            .with_value(
+                |custom_request_animation_frame| match custom_request_animation_frame {
+                    None => {
+                        let this = *self;
+                        let handle = request_animation_frame_with_handle(move || {
+                            this.animation_frame_arrived()
+                        })
+                        .map_err(|error| {
+                            AnimationError::AnimationFrameRequest(format!("{error:?}"))
+                        })?;
+                        self.state
+                            .set_value(AnimationContextState::AnimationFrameRequested(handle));
+                        Ok(())
+                    }
+                    Some(callback) => {
+                        self.state
+                            .set_value(AnimationContextState::CustomAnimationFrameRequested);
+                        callback();
+                        Ok(())
+                    }
+                },
+            )
+    }
+
+    /// Limit how often animated signals update, for example to save power on high refresh rate
+    /// displays. Animation frames that arrive too early are skipped, the animations themselves keep
+    /// their timing. `None` updates on every animation frame, which is the default. Frame rates that
+    /// are not a positive number are treated as `None`.
+    pub fn set_target_frame_rate(&self, frames_per_second: Option<f64>) {
+        self.set_min_frame_interval(
+            frames_per_second
+                .filter(|frames_per_second| *frames_per_second > 0.0)
+                .map(|frames_per_second| Duration::from_secs_f64(1.0 / frames_per_second)),
+        );
     }
+
+    /// Skip animation frames that arrive within `min_frame_interval` of the previous update, see
+    /// [`set_target_frame_rate()`](AnimationContext::set_target_frame_rate)
+    pub fn set_min_frame_interval(&self, min_frame_interval: Option<Duration>) {
+        self.min_frame_interval.set_value(min_frame_interval);
+    }
+
+    /// Call the callback after every update of the animated signals in this context with the time
+    /// the update took. The callback is removed again when the reactive scope it is registered in
+    /// is cleaned up.
+    /// ```
+    /// # use std::{cell::Cell, rc::Rc, time::Duration};
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animation, AnimationContext};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let updates = Rc::new(Cell::new(0));
+    /// context.on_frame({
+    ///     let updates = updates.clone();
+    ///     move |_update_time| updates.set(updates.get() + 1)
+    /// });
+    /// context.set_target_frame_rate(Some(30.0));
+    ///
+    /// let (target, set_target) = create_signal(0.0);
+    /// let value = create_animation::<f64>(move || (target.get(), Duration::from_secs(1)).into());
+    /// set_target.set(100.0);
+    ///
+    /// // Animation frames arrive every 10 milliseconds, but only every fourth one updates
+    /// for _ in 0..8 {
+    ///     clock.advance(Duration::from_millis(10));
+    ///     animation_frame();
+    /// }
+    /// assert_eq!(updates.get(), 2);
+    /// # runtime.dispose();
+    /// ```
+    pub fn on_frame(&self, callback: impl Fn(Duration) + 'static) {
+        let frame_hook: FrameHook = Rc::new(callback);
+        self.frame_hooks
+            .update_value(|frame_hooks| frame_hooks.push(frame_hook.clone()));
+
+        let frame_hooks = self.frame_hooks;
+        on_cleanup(move || {
+            // The context may have been cleaned up already
+            frame_hooks.try_update_value(|frame_hooks| {
+                frame_hooks.retain(|other| !Rc::ptr_eq(other, &frame_hook))
+            });
+        });
+    }
+
+    /// Update the animated signals when a requested animation frame arrives, unless it arrives
+    /// before the minimum frame interval has passed
+    fn animation_frame_arrived(&self) {
+        self.state
+            .set_value(AnimationContextState::NoAnimationFrameRequested);
+
+        if let Some(min_frame_interval) = self.min_frame_interval.get_value() {
+            let now = self.clock_now();
+            let too_early = self.last_frame.get_value().is_some_and(|last_frame| {
+                now.saturating_duration_since(last_frame) + FRAME_INTERVAL_TOLERANCE
+                    < min_frame_interval
+            });
+            if too_early {
+                self.request_animation_frame();
+                return;
+            }
+            self.last_frame.set_value(Some(now));
+        }
+
+        self.update();
+    }
+
+    /// Update all animated signals and report how long it took to the frame hooks
+    fn update(&self) {
+        let started = Instant::now();
+        self.animation_frame.notify();
+        let update_time = started.elapsed();
+
+        let frame_hooks = self.frame_hooks.with_value(Clone::clone);
+        for frame_hook in frame_hooks {
+            frame_hook(update_time);
+        }
+    }
+
+    /// The context that is provided in a parent scope
+    pub(crate) fn try_use() -> Result<AnimationContext, AnimationError> {
+        use_context().ok_or(AnimationError::MissingContext)
+    }
+
+    /// The context that is provided in a parent scope. With the `implicit-context` feature the
+    /// [implicit context](AnimationContext::implicit) is used when there is none, otherwise this
+    /// panics.
+    pub(crate) fn expect_use() -> AnimationContext {
+        match Self::try_use() {
+            Ok(context) => context,
+            #[cfg(feature = "implicit-context")]
+            Err(_) => Self::implicit(),
+            #[cfg(not(feature = "implicit-context"))]
+            Err(error) => panic!("{error}, call AnimationContext::provide() in a parent scope"),
+        }
+    }
+
+    /// The context that is shared by all scopes of the current runtime without a provided
+    /// context. It is created the first time it is needed, in the scope that needs it, and
+    /// replaced when that scope has been disposed.
+    #[cfg(feature = "implicit-context")]
+    fn implicit() -> AnimationContext {
+        let runtime = leptos::current_runtime();
+        let shared = IMPLICIT_CONTEXT.get().filter(|(context_runtime, context)| {
+            *context_runtime == runtime && context.state.try_with_value(|_| ()).is_some()
+        });
+        match shared {
+            Some((_, context)) => context,
+            None => {
+                let context = Self::provide();
+                IMPLICIT_CONTEXT.set(Some((runtime, context)));
+                context
+            }
+        }
+    }
+}
+
+#[cfg(feature = "implicit-context")]
+thread_local! {
+    /// The [implicit context](AnimationContext::implicit) and the runtime it belongs to
+    static IMPLICIT_CONTEXT: std::cell::Cell<Option<(leptos::RuntimeId, AnimationContext)>> =
+        const { std::cell::Cell::new(None) };
+}
+
+/// Errors returned by the fallible versions of functions in this crate, such as
+/// [`try_create_animated_signal()`] and [`AnimationContext::try_request_animation_frame()`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AnimationError {
+    /// There is no [`AnimationContext`] in a parent scope
+    MissingContext,
+
+    /// The browser refused to request an animation frame, contains the error it gave
+    AnimationFrameRequest(String),
+}
+
+impl Display for AnimationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnimationError::MissingContext => write!(f, "No AnimationContext present"),
+            AnimationError::AnimationFrameRequest(error) => {
+                write!(f, "Failed to request an animation frame: {error}")
+            }
+        }
+    }
+}
+
+impl Error for AnimationError {}
+
+/// Keep the motion policy up to date with the `prefers-reduced-motion` media query of the browser
+#[cfg(target_arch = "wasm32")]
+fn follow_prefers_reduced_motion(system_motion_policy: RwSignal<MotionPolicy>) {
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let Some(query) = leptos::window()
+        .match_media("(prefers-reduced-motion: reduce)")
+        .ok()
+        .flatten()
+    else {
+        return;
+    };
+
+    let update = move |reduce: bool| {
+        system_motion_policy.set(if reduce {
+            MotionPolicy::Reduced
+        } else {
+            MotionPolicy::Full
+        })
+    };
+    update(query.matches());
+
+    let on_change = Closure::<dyn Fn(web_sys::MediaQueryListEvent)>::new(
+        move |event: web_sys::MediaQueryListEvent| update(event.matches()),
+    );
+    query.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+    on_cleanup(move || {
+        query.set_onchange(None);
+        drop(on_change);
+    });
+}
+
+/// A signal that follows the Page Visibility API of the browser
+#[cfg(target_arch = "wasm32")]
+fn page_visibility() -> Signal<bool> {
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let document = leptos::document();
+    let visible = create_rw_signal(!document.hidden());
+
+    let on_change = Closure::<dyn Fn()>::new({
+        let document = document.clone();
+        move || visible.set(!document.hidden())
+    });
+    let _ = document
+        .add_event_listener_with_callback("visibilitychange", on_change.as_ref().unchecked_ref());
+    on_cleanup(move || {
+        let _ = document.remove_event_listener_with_callback(
+            "visibilitychange",
+            on_change.as_ref().unchecked_ref(),
+        );
+        drop(on_change);
+    });
+
+    visible.into()
 }
 
 /// An `AnimationTarget` is a target value for the animation system to ease towards to along with
 /// details about the animation such as its duration, easing method and how to deal with previous animations.
 ///
+/// An `AnimationTarget` is built from a target value, any option that is not set gets a default value:
+/// ```
+/// # use std::time::Duration;
+/// # use leptos_animation::{AnimationMode, AnimationTarget, easing};
+/// let _ = AnimationTarget::new(42)
+///     .duration(Duration::from_secs_f64(1.5))
+///     .easing(easing::ELASTIC_IN)
+///     .mode(AnimationMode::ReplaceOrStart)
+///     .delay(Duration::from_millis(100));
+/// ```
+///
 /// An AnimationTarget can also be created from a tuple:
 /// ```
 /// # use std::time::Duration;
@@ -184,70 +798,920 @@ impl AnimationContext {
 /// let _: AnimationTarget<u32> = (42, Duration::from_secs_f64(1.5)).into();
 /// ```
 ///
+/// Options that are not given are taken from the [`AnimationDefaults`] of the current scope.
+///
 /// If you want to use all the default animation options you can call `into()` directly on a target value:
 /// ```
 /// # use std::time::Duration;
 /// # use leptos_animation::AnimationTarget;
 /// let _: AnimationTarget<u32> = 42.into();
 /// ```
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+///
+/// # Copying and comparing
+///
+/// Unlike in version 0.5, an `AnimationTarget` is not `Copy` or `Eq`. It can own
+/// [keyframes](AnimationTarget::keyframes) and [closure easings](Easing::new), which can't be
+/// copied, and [repeat counts](Repeat::Count) and [springs](Spring) are floating point numbers.
+/// Call `clone()` where a target was copied, targets can still be compared with `==`.
+/// ```
+/// # use leptos_animation::{AnimationTarget, Repeat};
+/// let target = AnimationTarget::new(42).repeat(Repeat::Count(2.0));
+/// let copy = target.clone();
+/// assert_eq!(target, copy);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
 pub struct AnimationTarget<T> {
     /// The final value to animate towards to
     pub target: T,
 
-    /// The time for which the animation plays. Defaults to 0.5 seconds
-    pub duration: Duration,
+    /// The time for which the animation plays. Defaults to 0.5 seconds
+    pub duration: Duration,
+
+    /// The easing method to apply during the animation. Defaults to [`SINE_OUT`](easing::SINE_OUT)
+    pub easing: Easing,
+
+    /// Animate with a physics based spring instead of a fixed duration and easing. When set the
+    /// `duration` and `easing` are ignored and the animation ends when the spring comes to rest.
+    /// Defaults to `None`
+    pub spring: Option<Spring>,
+
+    /// Intermediate values to pass through on the way to the `target`, see [`Keyframe`] for details.
+    /// Defaults to no keyframes
+    pub keyframes: Vec<Keyframe<T>>,
+
+    /// The mode specifies how to deal with running animation. Defaults to [`Start`](AnimationMode::Start).
+    /// This can be used to add, overwrite or cancel running animations.
+    /// See [`AnimationMode`] for more information
+    pub mode: AnimationMode,
+
+    /// The time to wait before the animation starts, the output holds its current value until then.
+    /// Defaults to no delay
+    pub delay: Duration,
+
+    /// The number of times the animation plays, like CSS `animation-iteration-count`.
+    /// Defaults to playing once
+    pub repeat: Repeat,
+
+    /// Whether the animation plays forwards, backwards or alternates between the two on each repetition,
+    /// like CSS `animation-direction`. Defaults to [`Normal`](AnimationDirection::Normal)
+    pub direction: AnimationDirection,
+}
+
+/// The number of times an animation plays, see [`AnimationTarget::repeat`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Repeat {
+    /// Play the animation this many times. Fractions play part of the last iteration,
+    /// `Repeat::Count(1.5)` plays the animation once and then stops halfway through the second time.
+    Count(f64),
+
+    /// Keep playing the animation until it is replaced or cancelled
+    Infinite,
+}
+
+/// The direction in which each iteration of an animation plays, see [`AnimationTarget::direction`]
+///
+/// Like CSS animations an animation that does not end at its target jumps to the target when it finishes.
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animation, easing, AnimationContext, AnimationDirection, AnimationTarget, Repeat};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// let (scale, set_scale) = create_signal(1.0);
+/// let pulse = create_animation::<f64>(move || {
+///     AnimationTarget::from((scale.get(), Duration::from_secs(1), easing::LINEAR))
+///         .delay(Duration::from_secs(1))
+///         .repeat(Repeat::Count(3.0))
+///         .direction(AnimationDirection::Alternate)
+/// });
+///
+/// set_scale.set(2.0);
+/// clock.advance(Duration::from_millis(1500));
+/// animation_frame();
+/// assert_eq!(pulse.get_untracked(), 1.5);
+///
+/// // The second iteration plays backwards
+/// clock.advance(Duration::from_millis(750));
+/// animation_frame();
+/// assert_eq!(pulse.get_untracked(), 1.75);
+///
+/// clock.advance(Duration::from_millis(1750));
+/// animation_frame();
+/// assert_eq!(pulse.get_untracked(), 2.0);
+/// assert!(!pulse.is_running().get_untracked());
+/// # runtime.dispose();
+/// ```
+///
+/// During the delay the output holds the value the animation starts from, also when the first
+/// iteration plays backwards:
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animation, easing, AnimationContext, AnimationDirection, AnimationTarget};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// let (scale, set_scale) = create_signal(1.0);
+/// let value = create_animation::<f64>(move || {
+///     AnimationTarget::from((scale.get(), Duration::from_secs(1), easing::LINEAR))
+///         .delay(Duration::from_secs(1))
+///         .direction(AnimationDirection::Reverse)
+/// });
+///
+/// set_scale.set(2.0);
+/// clock.advance(Duration::from_millis(500));
+/// animation_frame();
+/// assert_eq!(value.get_untracked(), 1.0);
+///
+/// clock.advance(Duration::from_millis(750));
+/// animation_frame();
+/// assert_eq!(value.get_untracked(), 1.75);
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AnimationDirection {
+    /// Every iteration plays from start to end
+    Normal,
+
+    /// Every iteration plays from end to start
+    Reverse,
+
+    /// The first iteration plays from start to end, the next one back from end to start and so on
+    Alternate,
+
+    /// The first iteration plays from end to start, the next one from start to end and so on
+    AlternateReverse,
+}
+
+/// The `AnimationMode` specifies how to handle new animation target values with respect to currently running animations
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AnimationMode {
+    /// Always start a new animation on top of the already running animations when the input signal changes.
+    /// This is the default mode. For 'bursty' input signals which can update many times in quick succession (like mouse move events)
+    /// it is recommended to use one of the other modes to prevent many overlapping animations running simultaneously
+    Start,
+
+    /// Replace the target value of the latest running animation or start a new animation if there are no animations running.
+    /// The keyframes of the latest running animation are replaced as well. When the latest animation has already
+    /// finished while older animations are still running, a new animation is started instead.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animation, easing, AnimationContext, AnimationMode, AnimationTarget};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (target, set_target) = create_signal(AnimationTarget::new(0.0));
+    /// let value = create_animation::<f64>(move || target.get());
+    /// let animate = |value, millis, mode| {
+    ///     let duration = Duration::from_millis(millis);
+    ///     AnimationTarget::new(value).duration(duration).easing(easing::LINEAR).mode(mode)
+    /// };
+    ///
+    /// set_target.set(animate(100.0, 1000, AnimationMode::Start));
+    /// clock.advance(Duration::from_millis(250));
+    /// animation_frame();
+    /// set_target.set(animate(200.0, 500, AnimationMode::Start));
+    ///
+    /// // The second animation has finished, the first one is still running
+    /// clock.advance(Duration::from_millis(550));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 180.0);
+    ///
+    /// set_target.set(animate(300.0, 1000, AnimationMode::ReplaceOrStart));
+    /// clock.advance(Duration::from_millis(100));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 200.0);
+    /// # runtime.dispose();
+    /// ```
+    ReplaceOrStart,
+
+    /// Replace the target of the latest running animation or snap directly to the target if there are no animations running.
+    /// The keyframes of the latest running animation are replaced as well
+    ReplaceOrSnap,
+
+    /// Cancels any previous animation and sets the output directly to the target value, keyframes are skipped
+    Snap,
+
+    /// Replace all running animations with a single animation from the current value to the target, that starts
+    /// off with the velocity the value currently has. Useful for input signals that continuously change their target
+    /// (like following the mouse or the scroll position) where neither a pileup of animations nor a sudden change
+    /// in direction is wanted. Keyframes are skipped.
+    ///
+    /// The new animation starts from the current value, which only signals created with [`create_animation()`]
+    /// can [start from](create_animation#starting-from-the-current-value). Signals created with
+    /// [`create_animated_signal()`] panic on this mode in debug builds. In release builds they keep the running
+    /// animations and treat it the same as [`Start`](AnimationMode::Start).
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animation, easing, AnimationContext, AnimationMode};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (target, set_target) = create_signal(0.0);
+    /// let value = create_animation::<f64>(move || {
+    ///     (target.get(), Duration::from_secs(1), easing::LINEAR, AnimationMode::Retarget).into()
+    /// });
+    ///
+    /// set_target.set(100.0);
+    /// clock.advance(Duration::from_millis(500));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 50.0);
+    ///
+    /// // Turning around continues smoothly from the current value and keeps moving up for a bit
+    /// set_target.set(0.0);
+    /// assert_eq!(value.get_untracked(), 50.0);
+    /// clock.advance(Duration::from_millis(10));
+    /// animation_frame();
+    /// assert!(value.get_untracked() > 50.0);
+    ///
+    /// clock.advance(Duration::from_secs(1));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 0.0);
+    /// # runtime.dispose();
+    /// ```
+    ///
+    /// The velocity is kept in the [`Delta`](Animatable::Delta) of the value, so it isn't cut off when
+    /// the value briefly moves past the bounds of its type, such as for this `u8`:
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animation, easing, AnimationContext, AnimationMode};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (target, set_target) = create_signal(0_u8);
+    /// let value = create_animation::<u8>(move || {
+    ///     (target.get(), Duration::from_secs(1), easing::LINEAR, AnimationMode::Retarget).into()
+    /// });
+    ///
+    /// set_target.set(255);
+    /// clock.advance(Duration::from_millis(500));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 128);
+    ///
+    /// // Keeps moving up towards the top of the range before turning around
+    /// set_target.set(0);
+    /// clock.advance(Duration::from_millis(350));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 250);
+    ///
+    /// clock.advance(Duration::from_secs(1));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 0);
+    /// # runtime.dispose();
+    /// ```
+    ///
+    /// A signal created with [`create_animated_signal()`] can't retarget:
+    /// ```should_panic
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animated_signal, tween_default, AnimationContext, AnimationMode};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (target, set_target) = create_signal(0.0);
+    /// let value = create_animated_signal(
+    ///     move || (target.get(), AnimationMode::Retarget).into(),
+    ///     tween_default::<f64, f64>,
+    /// );
+    /// ```
+    Retarget,
+}
+
+/// What happens when more animations are stacked on top of each other than an animated signal allows,
+/// see [`AnimatedSignal::set_stack_limit()`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StackPolicy {
+    /// Collapse the oldest animations into a single animation that starts at their current value.
+    ///
+    /// The collapsed animation starts from the value of the oldest animations and ends when the newest of
+    /// them would have ended.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animation, easing, AnimationContext, StackPolicy};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (target, set_target) = create_signal(0.0);
+    /// let value = create_animation::<f64>(move || (target.get(), Duration::from_secs(1), easing::LINEAR).into());
+    /// value.set_stack_limit(2, StackPolicy::Merge);
+    ///
+    /// set_target.set(100.0);
+    /// clock.advance(Duration::from_millis(500));
+    /// animation_frame();
+    /// set_target.set(200.0);
+    /// clock.advance(Duration::from_millis(250));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 100.0);
+    ///
+    /// // The two running animations are merged into one from 100 to 200 that ends in 750 milliseconds.
+    /// // Halfway it is 50 below 200 and the new animation is 62.5 below 300.
+    /// set_target.set(300.0);
+    /// clock.advance(Duration::from_millis(375));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 187.5);
+    ///
+    /// clock.advance(Duration::from_millis(375));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 275.0);
+    /// # runtime.dispose();
+    /// ```
+    ///
+    /// Only signals created with [`create_animation()`] can [start from](create_animation#starting-from-the-current-value)
+    /// a value. [`AnimatedSignal::set_stack_limit()`] panics in debug builds when this policy is used with a signal
+    /// created with [`create_animated_signal()`]. In release builds such a signal replaces the target of the latest
+    /// animation instead, the same as [`Replace`](StackPolicy::Replace).
+    Merge,
+
+    /// Remove the oldest animations. The output jumps by the distance they still had to animate.
+    Drop,
+
+    /// Don't start a new animation but replace the target of the latest running animation,
+    /// the same as [`AnimationMode::ReplaceOrStart`]
+    Replace,
+}
+
+#[derive(Clone, Copy)]
+struct StackLimit {
+    max: usize,
+    policy: StackPolicy,
+}
+
+/// Unlimited, so that every change with [`AnimationMode::Start`] adds an animation
+impl Default for StackLimit {
+    fn default() -> Self {
+        StackLimit {
+            max: usize::MAX,
+            policy: StackPolicy::Merge,
+        }
+    }
+}
+
+/// An easing function is one that takes a value between 0.0 - 1.0 and maps it to another value between 0.0 and 1.0
+/// See `https://easings.net` for a list of implemented functions
+///
+/// The functions in the [`easing`] module are plain `fn(f64) -> f64` functions that can be called
+/// directly, and they convert into an `Easing` wherever one is expected.
+/// ```
+/// # use leptos_animation::{easing, Easing};
+/// assert_eq!(easing::QUAD_IN(0.5), 0.25);
+/// assert_eq!(Easing::from(easing::QUAD_IN).apply(0.5), 0.25);
+/// ```
+///
+/// Besides plain functions an easing can also be a CSS `cubic-bezier()` or `steps()` timing function,
+/// see [`easing::cubic_bezier()`] and [`easing::steps()`].
+///
+/// Closures that capture state can be used with [`Easing::new()`]. Easings are cheap to clone.
+/// ```
+/// # use leptos_animation::Easing;
+/// let overshoot = 1.5;
+/// let easing = Easing::new(move |t| t * overshoot);
+/// assert_eq!(easing.apply(0.5), 0.75);
+/// ```
+#[derive(Clone)]
+pub enum Easing {
+    /// An easing defined by a function, such as the ones in the [`easing`] module
+    Function(fn(f64) -> f64),
+
+    /// An easing defined by a closure, see [`Easing::new()`]
+    Closure(Rc<dyn Fn(f64) -> f64>),
+
+    /// A cubic Bézier curve from (0, 0) to (1, 1) with control points (x1, y1) and (x2, y2)
+    CubicBezier { x1: f64, y1: f64, x2: f64, y2: f64 },
+
+    /// Jumps between a number of equal steps
+    Steps {
+        steps: u32,
+        position: easing::StepPosition,
+    },
+}
+
+impl Easing {
+    /// Create an easing from a closure
+    pub fn new(easing: impl Fn(f64) -> f64 + 'static) -> Easing {
+        Easing::Closure(Rc::new(easing))
+    }
+
+    /// Map the linear progress `t` to the eased progress
+    pub fn apply(&self, t: f64) -> f64 {
+        match *self {
+            Easing::Function(function) => function(t),
+            Easing::Closure(ref closure) => closure(t),
+            Easing::CubicBezier { x1, y1, x2, y2 } => {
+                easing::evaluate_cubic_bezier(x1, y1, x2, y2, t)
+            }
+            Easing::Steps { steps, position } => easing::evaluate_steps(steps, position, t),
+        }
+    }
+}
+
+impl Debug for Easing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Easing::Function(function) => f.debug_tuple("Function").field(function).finish(),
+            Easing::Closure(_) => f.debug_tuple("Closure").finish_non_exhaustive(),
+            Easing::CubicBezier { x1, y1, x2, y2 } => f
+                .debug_struct("CubicBezier")
+                .field("x1", x1)
+                .field("y1", y1)
+                .field("x2", x2)
+                .field("y2", y2)
+                .finish(),
+            Easing::Steps { steps, position } => f
+                .debug_struct("Steps")
+                .field("steps", steps)
+                .field("position", position)
+                .finish(),
+        }
+    }
+}
+
+/// Easings are equal when they are the same function, the same closure or have the same parameters
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Easing::Function(a), Easing::Function(b)) => std::ptr::fn_addr_eq(*a, *b),
+            (Easing::Closure(a), Easing::Closure(b)) => Rc::ptr_eq(a, b),
+            (
+                Easing::CubicBezier { x1, y1, x2, y2 },
+                Easing::CubicBezier {
+                    x1: b_x1,
+                    y1: b_y1,
+                    x2: b_x2,
+                    y2: b_y2,
+                },
+            ) => (x1, y1, x2, y2) == (b_x1, b_y1, b_x2, b_y2),
+            (
+                Easing::Steps { steps, position },
+                Easing::Steps {
+                    steps: b_steps,
+                    position: b_position,
+                },
+            ) => (steps, position) == (b_steps, b_position),
+            _ => false,
+        }
+    }
+}
+
+impl From<fn(f64) -> f64> for Easing {
+    fn from(function: fn(f64) -> f64) -> Self {
+        Easing::Function(function)
+    }
+}
+
+/// A tween function as given to [`create_animated_signal()`]
+type Tween<T, I> = dyn Fn(&T, &T, f64) -> I;
+
+/// Combines the value all animations end at with the layers of the running animations, oldest first
+type Compose<T, I> = dyn Fn(&I, &[Layer<T, I>], &Tween<T, I>) -> I;
+
+/// What a single running animation contributes to the animated value
+struct Layer<'a, T, I> {
+    /// The value the animation ends at
+    to_i: &'a I,
+    /// The start of the segment the animation is in
+    from: &'a T,
+    /// The end of the segment the animation is in
+    to: &'a T,
+    /// How far the animation is in the segment, after easing
+    progress: f64,
+}
+
+/// The time over which the velocity of a value is measured when retargeting
+const VELOCITY_SAMPLE: Duration = Duration::from_millis(1);
+
+/// The curve that maps the elapsed time of an animation to its progress
+#[derive(Clone)]
+enum Curve {
+    Easing(Easing),
+    Spring(Spring),
+}
+
+struct Animation<T, I> {
+    from: T,
+    to: T,
+    to_i: I,
+    keyframes: Vec<Keyframe<T>>,
+    start: Instant,
+    /// Time the animation had already played at `start`, for animations that were moved to a
+    /// point before the earliest `Instant` (the page load in the browser)
+    head_start: Duration,
+    duration: Duration,
+    curve: Curve,
+    /// The number of iterations, infinite for animations that repeat forever
+    iterations: f64,
+    direction: AnimationDirection,
+    /// Reversed animations play from their end back to their start
+    reversed: bool,
+}
+
+impl<T, I> Animation<T, I> {
+    fn new(from: T, animation_target: &AnimationTarget<T>, to_i: I, start: Instant) -> Self
+    where
+        T: Clone,
+    {
+        let (duration, curve) = match animation_target.spring {
+            Some(spring) => (spring.settling_duration(), Curve::Spring(spring)),
+            None => (
+                animation_target.duration,
+                Curve::Easing(animation_target.easing.clone()),
+            ),
+        };
+
+        Animation {
+            from,
+            to: animation_target.target.clone(),
+            to_i,
+            keyframes: sorted_keyframes(&animation_target.keyframes),
+            start: start + animation_target.delay,
+            head_start: Duration::ZERO,
+            duration,
+            curve,
+            iterations: match animation_target.repeat {
+                Repeat::Count(count) => count.max(0.0),
+                Repeat::Infinite => f64::INFINITY,
+            },
+            direction: animation_target.direction,
+            reversed: false,
+        }
+    }
+
+    /// Change where the animation ends, keeping its timing
+    fn replace_target(&mut self, animation_target: &AnimationTarget<T>, to_i: I)
+    where
+        T: Clone,
+    {
+        self.to = animation_target.target.clone();
+        self.to_i = to_i;
+        self.keyframes = sorted_keyframes(&animation_target.keyframes);
+    }
+
+    /// Whether [`replace_target()`](Animation::replace_target) tweens to the new target. A reversed
+    /// animation does not end at its target and a finished animation has stopped moving.
+    fn can_replace_target(&self, now: Instant) -> bool {
+        !self.reversed && !self.is_finished(now)
+    }
+
+    /// The duration of all iterations together, `None` for animations that repeat forever
+    fn total_duration(&self) -> Option<Duration> {
+        self.iterations
+            .is_finite()
+            .then(|| self.duration.mul_f64(self.iterations))
+    }
+
+    /// The duration of all iterations, or of a single iteration for animations that repeat forever
+    fn span(&self) -> Duration {
+        self.total_duration().unwrap_or(self.duration)
+    }
+
+    fn is_finished(&self, now: Instant) -> bool {
+        match self.total_duration() {
+            Some(total_duration) => now + self.head_start >= self.start + total_duration,
+            None => false,
+        }
+    }
+
+    /// The time the animation has played for, ignoring reversal
+    fn played(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.start) + self.head_start
+    }
+
+    /// Move the start of the animation so that it has played for `played` at `now`
+    fn set_played(&mut self, now: Instant, played: Duration) {
+        (self.start, self.head_start) = match now.checked_sub(played) {
+            Some(start) => (start, Duration::ZERO),
+            None => (now, played),
+        };
+    }
+
+    /// The time since the start of the animation, counting backwards for reversed animations
+    fn elapsed(&self, now: Instant) -> Duration {
+        let elapsed = self.played(now);
+        match self.total_duration() {
+            Some(total_duration) if self.reversed => total_duration - elapsed.min(total_duration),
+            Some(total_duration) => elapsed.min(total_duration),
+            None => elapsed,
+        }
+    }
+
+    /// How far the animation is between 0.0 and 1.0, for animations that repeat forever
+    /// how far the current iteration is
+    fn progress(&self, now: Instant) -> f64 {
+        let elapsed = self.elapsed(now).as_secs_f64();
+        let span = self.span().as_secs_f64();
+        let progress = if span == 0.0 {
+            1.0
+        } else if self.iterations.is_finite() {
+            (elapsed / span).min(1.0)
+        } else {
+            (elapsed / span).fract()
+        };
+
+        if self.reversed {
+            1.0 - progress
+        } else {
+            progress
+        }
+    }
+
+    /// The position in the current iteration between 0.0 and 1.0, before any easing is applied.
+    /// Springs may overshoot beyond 1.0.
+    fn position(&self, now: Instant) -> f64 {
+        // The output holds the value the animation starts from during the delay, whichever
+        // direction the first iteration plays in
+        if now < self.start {
+            return 0.0;
+        }
+
+        let duration = self.duration.as_secs_f64();
+        let iterations = self.elapsed(now).as_secs_f64() / duration;
+
+        // The iteration the animation is in and how far along it is
+        let (iteration, fraction) = if iterations < self.iterations {
+            (iterations.floor(), iterations.fract())
+        } else if self.iterations.is_finite() {
+            let iteration = (self.iterations.ceil() - 1.0).max(0.0);
+            (iteration, self.iterations - iteration)
+        } else {
+            (0.0, 1.0)
+        };
+
+        let backwards = match self.direction {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => iteration % 2.0 == 1.0,
+            AnimationDirection::AlternateReverse => iteration % 2.0 == 0.0,
+        };
+        let position = if backwards { 1.0 - fraction } else { fraction };
+
+        match self.curve {
+            Curve::Easing(_) => position,
+            Curve::Spring(_) if position >= 1.0 => 1.0,
+            Curve::Spring(spring) => spring.progress(position * duration, 0.0),
+        }
+    }
+
+    /// Move the animation to the given position between 0.0 and 1.0
+    fn seek(&mut self, now: Instant, position: f64) {
+        let position = position.clamp(0.0, 1.0);
+        let position = if self.reversed {
+            1.0 - position
+        } else {
+            position
+        };
+        self.set_played(now, self.span().mul_f64(position));
+    }
+
+    /// Play the animation in the other direction from its current position. A reversed animation
+    /// ends at its `from` value. Animations that repeat forever play back to their start.
+    fn reverse(&mut self, now: Instant, tween: &Tween<T, I>) {
+        if !self.iterations.is_finite() {
+            let elapsed = self.played(now).as_secs_f64();
+            self.iterations = elapsed / self.duration.as_secs_f64();
+            if !self.iterations.is_finite() {
+                self.iterations = 0.0;
+            }
+        }
+
+        let elapsed = self.elapsed(now);
+        let total_duration = self.span();
+        self.reversed = !self.reversed;
+        self.set_played(now, total_duration - elapsed);
+        self.to_i = if self.reversed {
+            tween(&self.from, &self.from, 1.0)
+        } else {
+            tween(&self.to, &self.to, 1.0)
+        };
+    }
+
+    /// The value this animation ends at
+    fn end(&self) -> &T {
+        if self.reversed {
+            &self.from
+        } else {
+            &self.to
+        }
+    }
+
+    fn layer(&self, now: Instant) -> Layer<'_, T, I> {
+        if self.is_finished(now) {
+            let end = self.end();
+            return Layer {
+                to_i: &self.to_i,
+                from: end,
+                to: end,
+                progress: 1.0,
+            };
+        }
+
+        let position = self.position(now);
+        let linear = Easing::Function(easing::LINEAR);
+        let easing = match &self.curve {
+            Curve::Easing(easing) => easing,
+            Curve::Spring(_) => &linear,
+        };
+
+        let (from, to, progress) = if self.keyframes.is_empty() {
+            (&self.from, &self.to, easing.apply(position))
+        } else {
+            keyframe_segment(&self.from, &self.keyframes, &self.to, easing, position)
+        };
+        Layer {
+            to_i: &self.to_i,
+            from,
+            to,
+            progress,
+        }
+    }
+}
+
+/// The animations that take over from `value` towards the target, starting off with the velocity
+/// the value had since `previous`, [`VELOCITY_SAMPLE`] ago.
+///
+/// The first animation moves to the target with an easing whose initial slope is cancelled out,
+/// the second animation contributes the initial velocity and fades out over the same duration.
+fn retarget_animations<T, I>(
+    value: T,
+    previous: T,
+    animation_target: &AnimationTarget<T>,
+    now: Instant,
+    tween: &Tween<T, I>,
+) -> VecDeque<Animation<T, I>>
+where
+    T: Clone,
+{
+    let to_i = tween(&animation_target.target, &animation_target.target, 1.0);
+    let mut towards = Animation::new(value.clone(), animation_target, to_i, now);
+    towards.keyframes.clear();
 
-    /// The easing method to apply during the animation. Defaults to [`SINE_OUT`](easing::SINE_OUT)
-    pub easing: Easing,
+    let sample = VELOCITY_SAMPLE.as_secs_f64();
+    let velocity_easing = match &towards.curve {
+        Curve::Easing(easing) => {
+            let easing = easing.clone();
+            let slope = (easing.apply(sample) - easing.apply(0.0)) / sample;
+            towards.curve = Curve::Easing(Easing::new(move |t| {
+                easing.apply(t) - slope * hermite_velocity(t)
+            }));
 
-    /// The mode specifies how to deal with running animation. Defaults to [`Start`](AnimationMode::Start).
-    /// This can be used to add, overwrite or cancel running animations.
-    /// See [`AnimationMode`] for more information
-    pub mode: AnimationMode,
-}
+            let scale = towards.duration.as_secs_f64() / sample;
+            Easing::new(move |t| -scale * hermite_velocity(t))
+        }
+        Curve::Spring(spring) => {
+            let spring = *spring;
+            let duration = towards.duration.as_secs_f64();
+            Easing::new(move |t| -spring.impulse(t * duration) / sample)
+        }
+    };
 
-/// The `AnimationMode` specifies how to handle new animation target values with respect to currently running animations
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum AnimationMode {
-    /// Always start a new animation on top of the already running animations when the input signal changes.
-    /// This is the default mode. For 'bursty' input signals which can update many times in quick succession (like mouse move events)
-    /// it is recommended to use one of the other modes to prevent many overlapping animations running simultaneously
-    Start,
+    let velocity = Animation {
+        from: value.clone(),
+        to: previous,
+        to_i: tween(&value, &value, 1.0),
+        keyframes: Vec::new(),
+        start: now,
+        head_start: Duration::ZERO,
+        duration: towards.duration,
+        curve: Curve::Easing(velocity_easing),
+        iterations: 1.0,
+        direction: AnimationDirection::Normal,
+        reversed: false,
+    };
 
-    /// Replace the target value of the latest running animation or start a new animation if there are no animations running
-    ReplaceOrStart,
+    VecDeque::from([towards, velocity])
+}
 
-    /// Replace the target of the latest running animation or snap directly to the target if there are no animations running
-    ReplaceOrSnap,
+/// Hermite basis function that starts and ends at 0.0 with a slope of 1.0 at the start and 0.0 at the end
+fn hermite_velocity(t: f64) -> f64 {
+    t * (1.0 - t) * (1.0 - t)
+}
 
-    /// Cancels any previous animation and sets the output directly to the target value
-    Snap,
+fn sorted_keyframes<T: Clone>(keyframes: &[Keyframe<T>]) -> Vec<Keyframe<T>> {
+    // Keyframes set directly on the field haven't been validated yet
+    let mut keyframes = valid_keyframes(keyframes.iter().cloned());
+    keyframes.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+    keyframes
 }
 
-/// An easing function is one that takes a value between 0.0 - 1.0 and maps it to another value between 0.0 and 1.0
-/// See `https://easings.net` for a list of implemented functions
-pub type Easing = fn(f64) -> f64;
+/// Add all animation results to a single value, the most recent animation last
+fn fold_animations<T, I: Clone>(
+    animations: &VecDeque<Animation<T, I>>,
+    to_i: &I,
+    now: Instant,
+    tween: &Tween<T, I>,
+    compose: &Compose<T, I>,
+) -> I {
+    let layers: Vec<_> = animations
+        .iter()
+        .rev()
+        .map(|animation| animation.layer(now))
+        .collect();
+    compose(to_i, &layers, tween)
+}
 
-struct Animation<T, I> {
-    from: T,
-    to: T,
-    to_i: I,
-    start: Instant,
-    duration: Duration,
-    easing: Easing,
+/// Subtract how far each animation still is from its end from the value all animations end at
+fn compose_tweened<T, I>(to_i: &I, layers: &[Layer<T, I>], tween: &Tween<T, I>) -> I
+where
+    I: Clone,
+    I: Sub<I, Output = I>,
+{
+    layers.iter().fold(to_i.clone(), |acc, layer| {
+        acc - (layer.to_i.clone() - tween(layer.from, layer.to, layer.progress))
+    })
 }
 
-impl<T, I> Animation<T, I> {
-    fn is_finished(&self) -> bool {
-        Instant::now() > self.start + self.duration
-    }
+/// Add the differences of all animations to the value they end at. The differences are added in
+/// one go, so values such as integers are only rounded once.
+fn compose_deltas<T: Animatable>(to: &T, layers: &[Layer<T, T>], _: &Tween<T, T>) -> T {
+    let deltas: Vec<_> = layers
+        .iter()
+        .map(|layer| {
+            layer
+                .from
+                .interpolate_delta(layer.to, layer.progress, layer.to_i)
+        })
+        .collect();
+    to.add_deltas(&deltas.iter().collect::<Vec<_>>())
+}
+
+/// Bring the number of animations back to the stack limit by merging or dropping the oldest ones
+fn limit_animations<T: Clone, I: Clone>(
+    animations: &mut VecDeque<Animation<T, I>>,
+    limit: StackLimit,
+    now: Instant,
+    tween: &Tween<T, I>,
+    compose: &Compose<T, I>,
+    to_target: Option<fn(&I) -> T>,
+) {
+    // Only animations that are still running count towards the limit, the finished ones that
+    // are kept in between are dropped or merged together with the running ones behind them
+    let max = limit.max.max(1);
+    let running = |nth| {
+        animations
+            .iter()
+            .enumerate()
+            .filter(|(_, animation)| !animation.is_finished(now))
+            .nth(nth)
+            .map(|(index, _)| index)
+    };
+    let (Some(merge_from), Some(drop_from)) = (running(max - 1), running(max)) else {
+        return;
+    };
 
-    fn progress(&self) -> f64 {
-        (self.easing)((Instant::now() - self.start).as_secs_f64() / self.duration.as_secs_f64())
+    match (limit.policy, to_target) {
+        (StackPolicy::Merge, Some(to_target)) => {
+            let merged = animations.split_off(merge_from);
+            let newest = merged.front().unwrap();
+            let value = to_target(&fold_animations(&merged, &newest.to_i, now, tween, compose));
+
+            // Restart from the combined value so the output stays continuous, and end when the
+            // newest merged animation would have ended. Springs and animations that repeat forever
+            // keep their own timing.
+            let (duration, iterations, direction) = match (&newest.curve, newest.total_duration()) {
+                (Curve::Easing(_), Some(total_duration)) => (
+                    total_duration.saturating_sub(newest.played(now)),
+                    1.0,
+                    AnimationDirection::Normal,
+                ),
+                _ => (newest.duration, newest.iterations, newest.direction),
+            };
+            animations.push_back(Animation {
+                from: value,
+                to: newest.end().clone(),
+                to_i: newest.to_i.clone(),
+                keyframes: Vec::new(),
+                start: now,
+                head_start: Duration::ZERO,
+                duration,
+                curve: newest.curve.clone(),
+                iterations,
+                direction,
+                reversed: false,
+            });
+        }
+        _ => animations.truncate(drop_from),
     }
 }
 
+/// The number of animations that have not finished yet
+fn running_count<T, I>(animations: &VecDeque<Animation<T, I>>, now: Instant) -> usize {
+    animations
+        .iter()
+        .filter(|animation| !animation.is_finished(now))
+        .count()
+}
+
 enum AnimationStatus<T, I> {
     /// No animation running
     Static(T),
@@ -259,7 +1723,8 @@ enum AnimationStatus<T, I> {
     /// Animations are running
     /// The `VecDeque` is guaranteed to contain at least one animation. All animations are guaranteed
     /// to be sorted in reverse order of when they started with the most recent one in front and
-    /// the oldest one in the back.
+    /// the oldest one in the back. Reversing the animations also reverses this order, so that the
+    /// animation in front always ends at `to`.
     Running {
         to: T,
         to_i: I,
@@ -268,18 +1733,119 @@ enum AnimationStatus<T, I> {
 }
 
 impl<T: Clone, I> AnimationStatus<T, I> {
-    fn remove_finished_animations(&mut self) {
+    fn remove_finished_animations(&mut self, now: Instant) {
         match self {
             AnimationStatus::Static(_) => {}
             AnimationStatus::Snap(value) => *self = AnimationStatus::Static(value.clone()),
             AnimationStatus::Running { to, animations, .. } => {
-                animations.retain(|animation| !animation.is_finished());
-                if animations.len() == 0 {
+                // Finished animations are only removed once the animations behind them have
+                // finished too, so the most recent animation keeps the upper hand for values that
+                // are replaced instead of added up, such as texts
+                let running = animations
+                    .iter()
+                    .rposition(|animation| !animation.is_finished(now));
+                animations.truncate(running.map_or(0, |index| index + 1));
+                if animations.is_empty() {
                     *self = AnimationStatus::Snap(to.clone());
                 }
             }
         }
     }
+
+    fn finish(&mut self) {
+        if let AnimationStatus::Running { to, .. } = self {
+            *self = AnimationStatus::Snap(to.clone());
+        }
+    }
+
+    fn seek(&mut self, now: Instant, position: f64) {
+        if let AnimationStatus::Running { animations, .. } = self {
+            for animation in animations {
+                animation.seek(now, position);
+            }
+        }
+    }
+
+    fn reverse(&mut self, now: Instant, tween: &Tween<T, I>) {
+        if let AnimationStatus::Running {
+            to,
+            to_i,
+            animations,
+        } = self
+        {
+            for animation in animations.iter_mut() {
+                animation.reverse(now, tween);
+            }
+            *animations = animations.drain(..).rev().collect();
+
+            let end = animations.front().unwrap().end();
+            *to_i = tween(end, end, 1.0);
+            *to = end.clone();
+        }
+    }
+}
+
+type Callback = Rc<dyn Fn()>;
+
+/// Called after each update of the animated signals with the time the update took
+type FrameHook = Rc<dyn Fn(Duration)>;
+
+/// Callbacks and futures that are waiting for an animated signal to start or stop running
+#[derive(Default)]
+struct Hooks {
+    on_start: Vec<Callback>,
+    on_complete: Vec<Callback>,
+    on_cancel: Vec<Callback>,
+    finished: Vec<Rc<RefCell<FinishedState>>>,
+
+    /// Set when the running animations are cancelled instead of played to completion
+    cancelled: bool,
+}
+
+impl Hooks {
+    /// Take everything that has to be notified now that the animations have stopped running
+    fn take_stopped(&mut self) -> (Vec<Callback>, Vec<Rc<RefCell<FinishedState>>>) {
+        let callbacks = if std::mem::take(&mut self.cancelled) {
+            self.on_cancel.clone()
+        } else {
+            self.on_complete.clone()
+        };
+        (callbacks, std::mem::take(&mut self.finished))
+    }
+}
+
+/// Run the callbacks outside of the hooks so that they are free to register new hooks
+fn run_start_hooks(hooks: StoredValue<Hooks>) {
+    let callbacks = hooks.with_value(|hooks| hooks.on_start.clone());
+    for callback in callbacks {
+        callback();
+    }
+}
+
+fn run_stop_hooks(hooks: StoredValue<Hooks>) {
+    let (callbacks, finished) = hooks
+        .try_update_value(Hooks::take_stopped)
+        .unwrap_or_default();
+    for callback in callbacks {
+        callback();
+    }
+    for finished in finished {
+        finished.borrow_mut().finish();
+    }
+}
+
+/// Clean up after an animated signal that is disposed, running animations are cancelled
+fn release(context: AnimationContext, is_running: Memo<bool>, hooks: StoredValue<Hooks>) {
+    if is_running.try_get_untracked().unwrap_or(false) {
+        context.active_count.try_update(|count| *count -= 1);
+        hooks.update_value(|hooks| hooks.cancelled = true);
+        run_stop_hooks(hooks);
+    }
+}
+
+/// The current time for an animated signal, which stands still while the signal is paused
+fn playback_now(context: AnimationContext, paused_at: StoredValue<Option<Instant>>) -> Instant {
+    paused_at.get_value().unwrap_or_else(|| context.now())
 }
 
 // This is used to filter signals with create_memo. Yes, a total hack.
@@ -310,14 +1876,15 @@ impl PartialEq for SignalUpdate {
 /// arguments: `from`, `to` and `progress`. Where `from` and `to` are the values from the input signal
 /// and the `progress` is a value between 0.0 - 1.0. The easing is already applied to the `progress`.
 /// The tween function is expected to do a linear interpolation between `from` & `to` and return the
-/// result.
+/// result. It can be a function or a closure that captures state.
 ///
 /// If the input is in any way numeric or supports the `Add`, `Sub` and `Mul<f64>` traits it is recommended
 /// to use the [`tween_default`] function as input which performs a simple `(to - from) * progress + from`.
 ///
 /// If you are dealing with structs that are composed of numbers (for example a `Position { x: f64, y: f64 }`)
 /// you can use the [derive_more](https://docs.rs/crate/derive_more/latest) crate to implement the necessary traits.
-/// This way you can still use the `tween_default` function.
+/// This way you can still use the `tween_default` function. Alternatively enable the `derive` feature,
+/// add `#[derive(Animate)]` to the struct and use [`create_animation()`] instead.
 ///
 /// This function is generic over two types: `T` and `I`.
 /// * `T` is the type of values that are animated between. Animations are always from a `T` towards another `T`
@@ -349,7 +1916,7 @@ impl PartialEq for SignalUpdate {
 /// ```
 /// # use std::time::Duration;
 /// # use leptos::*;
-/// # use leptos_animation::{AnimationContext, AnimationMode, AnimationTarget, create_animated_signal, easing, tween_default};
+/// # use leptos_animation::{AnimationContext, AnimationMode, AnimationTarget, create_animated_signal, easing, spring::Spring, tween_default};
 /// # let runtime = create_runtime();
 /// # AnimationContext::provide();
 /// let (value, set_value) = create_signal(42.0);
@@ -362,14 +1929,17 @@ impl PartialEq for SignalUpdate {
 ///
 /// // Custom duration, easing & mode
 /// let custom_value = create_animated_signal(
-///         move || AnimationTarget {
-///             target: value.get(),
-///             duration: Duration::from_secs_f64(1.5),
-///             easing: easing::ELASTIC_IN_OUT,
-///             mode: AnimationMode::ReplaceOrStart
+///         move || {
+///             AnimationTarget::new(value.get())
+///                 .duration(Duration::from_secs_f64(1.5))
+///                 .easing(easing::ELASTIC_IN_OUT)
+///                 .mode(AnimationMode::ReplaceOrStart)
 ///         },
 ///         tween_default);
 ///
+/// // Physics based spring
+/// let spring_value = create_animated_signal(move || (value.get(), Spring::default()).into(), tween_default::<f64, f64>);
+///
 /// // Custom tween function
 /// let tween_value = create_animated_signal(
 ///         move || value.get().into(),
@@ -377,55 +1947,232 @@ impl PartialEq for SignalUpdate {
 ///             (to - from) * progress + from
 ///         });
 ///
+/// // Tween functions can capture state
+/// let offset = 10.0;
+/// let offset_value = create_animated_signal(
+///         move || value.get().into(),
+///         move |from: &f64, to: &f64, progress| (to - from) * progress + from + offset);
+///
+/// # runtime.dispose();
+/// ```
+///
+/// Panics when there is no [`AnimationContext`] in a parent scope, see [`try_create_animated_signal()`].
+/// With the `implicit-context` feature one `AnimationContext` is created the first time it is
+/// needed and shared by all scopes without a provided context instead. It belongs to the scope in
+/// which it was created, provide a context in a parent scope when that scope can be disposed while
+/// other scopes are still animating.
+/// ```
+/// # #[cfg(feature = "implicit-context")] {
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{create_animated_signal, easing, tween_default, AnimationContext};
+/// # let runtime = create_runtime();
+/// let (target, set_target) = create_signal(0.0);
+/// let animate = move || {
+///     create_animated_signal(
+///         move || (target.get(), Duration::from_secs(1), easing::LINEAR).into(),
+///         tween_default::<f64, f64>,
+///     )
+/// };
+///
+/// // Two sibling scopes without a provided context
+/// let (first, context) = run_as_child(move || (animate(), use_context::<AnimationContext>().unwrap()));
+/// let second = run_as_child(animate);
+///
+/// // Stepping the context of the first scope animates the second scope as well
+/// context.step(Duration::ZERO);
+/// set_target.set(1.0);
+/// context.step(Duration::from_millis(500));
+/// assert_eq!((first.get_untracked(), second.get_untracked()), (0.5, 0.5));
 /// # runtime.dispose();
+/// # }
 /// ```
 pub fn create_animated_signal<T, I>(
     source: impl Fn() -> AnimationTarget<T> + 'static,
-    tween: fn(&T, &T, f64) -> I,
+    tween: impl Fn(&T, &T, f64) -> I + 'static,
 ) -> AnimatedSignal<T, I>
 where
     T: 'static,
     T: Clone,
+    I: 'static,
     I: Clone,
     I: Sub<I, Output = I>,
 {
-    let context: AnimationContext = use_context()
-        .expect("No AnimationContext present, call AnimationContext::provide() in a parent scope");
+    create_additive_signal(
+        AnimationContext::expect_use(),
+        source,
+        tween,
+        compose_tweened,
+        None,
+    )
+}
+
+/// Like [`create_animated_signal()`] but returns an error instead of panicking when there is no
+/// [`AnimationContext`] in a parent scope.
+/// ```
+/// # use leptos::*;
+/// # use leptos_animation::{try_create_animated_signal, tween_default, AnimationError};
+/// # let runtime = create_runtime();
+/// let result = try_create_animated_signal(move || 42.0.into(), tween_default::<f64, f64>);
+/// assert_eq!(result.err(), Some(AnimationError::MissingContext));
+/// # runtime.dispose();
+/// ```
+pub fn try_create_animated_signal<T, I>(
+    source: impl Fn() -> AnimationTarget<T> + 'static,
+    tween: impl Fn(&T, &T, f64) -> I + 'static,
+) -> Result<AnimatedSignal<T, I>, AnimationError>
+where
+    T: 'static,
+    T: Clone,
+    I: 'static,
+    I: Clone,
+    I: Sub<I, Output = I>,
+{
+    Ok(create_additive_signal(
+        AnimationContext::try_use()?,
+        source,
+        tween,
+        compose_tweened,
+        None,
+    ))
+}
+
+/// Create a derived signal that animates the value of the input signals, for types that implement
+/// [`Animatable`]. This works the same as [`create_animated_signal()`] but does not need a tween function.
+///
+/// # Starting from the current value
+///
+/// The value of an `Animatable` type is also a valid target, so a new animation can start from
+/// wherever the running animations currently are. That is what [`AnimationMode::Retarget`] and
+/// [`StackPolicy::Merge`] do. The tween function of [`create_animated_signal()`] can return a
+/// different type than its targets, which can't be turned back into a target to start from. Signals
+/// created with it panic on both in debug builds.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animation, easing, AnimationContext};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// let (position, set_position) = create_signal((0.0, [0_u8, 0]));
+/// let animated_position = create_animation::<(f64, [u8; 2])>(move || {
+///     (position.get(), Duration::from_secs(1), easing::LINEAR).into()
+/// });
+///
+/// set_position.set((10.0, [100, 255]));
+/// clock.advance(Duration::from_millis(500));
+/// animation_frame();
+/// assert_eq!(animated_position.get_untracked(), (5.0, [50, 128]));
+/// # runtime.dispose();
+/// ```
+pub fn create_animation<T>(
+    source: impl Fn() -> AnimationTarget<T> + 'static,
+) -> AnimatedSignal<T, T>
+where
+    T: Animatable + 'static,
+{
+    create_additive_signal(
+        AnimationContext::expect_use(),
+        source,
+        T::interpolate,
+        compose_deltas,
+        Some(T::clone),
+    )
+}
+
+/// Like [`create_animation()`] but returns an error instead of panicking when there is no
+/// [`AnimationContext`] in a parent scope.
+pub fn try_create_animation<T>(
+    source: impl Fn() -> AnimationTarget<T> + 'static,
+) -> Result<AnimatedSignal<T, T>, AnimationError>
+where
+    T: Animatable + 'static,
+{
+    Ok(create_additive_signal(
+        AnimationContext::try_use()?,
+        source,
+        T::interpolate,
+        compose_deltas,
+        Some(T::clone),
+    ))
+}
 
+/// Creates an animated signal where the result of each running animation is combined with `compose`.
+/// It takes the accumulated value, the value the animation ends at and its current value.
+/// When the tweened value can be turned back into a `T` with `to_target`, [`AnimationMode::Retarget`] is supported.
+fn create_additive_signal<T, I>(
+    context: AnimationContext,
+    source: impl Fn() -> AnimationTarget<T> + 'static,
+    tween: impl Fn(&T, &T, f64) -> I + 'static,
+    compose: impl Fn(&I, &[Layer<T, I>], &Tween<T, I>) -> I + 'static,
+    to_target: Option<fn(&I) -> T>,
+) -> AnimatedSignal<T, I>
+where
+    T: 'static,
+    T: Clone,
+    I: 'static,
+    I: Clone,
+{
     let source = Signal::derive(source);
+    let tween: StoredValue<Rc<Tween<T, I>>> = store_value(Rc::new(tween));
+    let compose: Rc<Compose<T, I>> = Rc::new(compose);
+    let update_compose = compose.clone();
 
     let animation_status = store_value(AnimationStatus::<T, I>::Static(
         source.get_untracked().target,
     ));
+    let paused_at = store_value(None);
+    let now = move || playback_now(context, paused_at);
+    // A signal instead of a trigger, so that it can be disposed
+    let status_changed = create_rw_signal(());
+    let hooks = store_value(Hooks::default());
+    let stack_limit = store_value(StackLimit::default());
 
-    // Effect that listens to changes in the source and updates the animation status
-    let update_animation_status_effect = create_effect(move |prev| {
+    // Effect that listens to changes in the source and updates the animation status. It also runs
+    // on the server, where it only keeps track of the target.
+    let update_animation_status_effect = create_isomorphic_effect(move |prev| {
         let animation_target = source.get();
+        debug_assert!(
+            animation_target.mode != AnimationMode::Retarget || to_target.is_some(),
+            "AnimationMode::Retarget needs a signal created with create_animation(), \
+             use another mode with create_animated_signal()"
+        );
 
         // Don't start an animation the very first run
         if prev.is_none() {
             return;
         }
 
+        if context.is_server() {
+            animation_status.set_value(AnimationStatus::Static(animation_target.target));
+            return;
+        }
+
+        let animation_target =
+            animation_target.with_motion_policy(context.motion_policy_untracked());
+
+        let tween = tween.get_value();
         animation_status.update_value(|animation_status| {
             match animation_status {
                 // Starting an animation from a non-running state
                 AnimationStatus::Static(state) | AnimationStatus::Snap(state) => {
                     match animation_target.mode {
-                        AnimationMode::Start | AnimationMode::ReplaceOrStart => {
+                        AnimationMode::Start
+                        | AnimationMode::ReplaceOrStart
+                        | AnimationMode::Retarget => {
                             let to_i =
                                 tween(&animation_target.target, &animation_target.target, 1.0);
                             *animation_status = AnimationStatus::Running {
                                 to: animation_target.target.clone(),
                                 to_i: to_i.clone(),
-                                animations: VecDeque::from([Animation {
-                                    from: state.clone(),
-                                    to: animation_target.target,
+                                animations: VecDeque::from([Animation::new(
+                                    state.clone(),
+                                    &animation_target,
                                     to_i,
-                                    start: Instant::now(),
-                                    duration: animation_target.duration,
-                                    easing: animation_target.easing,
-                                }]),
+                                    now(),
+                                )]),
                             }
                         }
                         AnimationMode::ReplaceOrSnap | AnimationMode::Snap => {
@@ -439,35 +2186,88 @@ where
                     to_i,
                     animations,
                 } => match animation_target.mode {
-                    AnimationMode::Start => {
+                    // This arm can only be reached when there are still live animations, so we perform the 'replace' operation.
+                    // Finished animations are kept while older ones still run, those are not replaced.
+                    AnimationMode::ReplaceOrStart | AnimationMode::ReplaceOrSnap
+                        if animations.front().unwrap().can_replace_target(now()) =>
+                    {
+                        *to = animation_target.target.clone();
+                        *to_i = tween(&animation_target.target, &animation_target.target, 1.0);
+                        animations
+                            .front_mut()
+                            .unwrap()
+                            .replace_target(&animation_target, to_i.clone());
+                    }
+                    AnimationMode::Retarget if to_target.is_some() => {
+                        let to_target = to_target.unwrap();
+                        let now = now();
+                        let previous_time = now.checked_sub(VELOCITY_SAMPLE).unwrap_or(now);
+                        let value = |time| {
+                            to_target(&fold_animations(
+                                animations,
+                                to_i,
+                                time,
+                                &*tween,
+                                &*update_compose,
+                            ))
+                        };
+
+                        *animations = retarget_animations(
+                            value(now),
+                            value(previous_time),
+                            &animation_target,
+                            now,
+                            &*tween,
+                        );
+                        *to = animation_target.target;
+                        *to_i = animations.front().unwrap().to_i.clone();
+                    }
+                    AnimationMode::Start
+                    | AnimationMode::ReplaceOrStart
+                    | AnimationMode::ReplaceOrSnap
+                    | AnimationMode::Retarget => {
                         let new_to_i =
                             tween(&animation_target.target, &animation_target.target, 1.0);
+                        let limit = stack_limit.get_value();
+                        let replace = limit.policy == StackPolicy::Replace
+                            || (limit.policy == StackPolicy::Merge && to_target.is_none());
+                        let now = now();
 
-                        animations.push_front(Animation {
-                            from: to.clone(),
-                            to: animation_target.target.clone(),
-                            to_i: new_to_i.clone(),
-                            start: Instant::now(),
-                            duration: animation_target.duration,
-                            easing: animation_target.easing,
-                        });
+                        if replace
+                            && running_count(animations, now) >= limit.max
+                            && animations.front().unwrap().can_replace_target(now)
+                        {
+                            animations
+                                .front_mut()
+                                .unwrap()
+                                .replace_target(&animation_target, new_to_i.clone());
+                        } else {
+                            animations.push_front(Animation::new(
+                                to.clone(),
+                                &animation_target,
+                                new_to_i.clone(),
+                                now,
+                            ));
+                            limit_animations(
+                                animations,
+                                limit,
+                                now,
+                                &*tween,
+                                &*update_compose,
+                                to_target,
+                            );
+                        }
                         *to = animation_target.target;
                         *to_i = new_to_i;
                     }
-                    // This arm can only be reached when there are still live animations, so we perform the 'replace' operation
-                    AnimationMode::ReplaceOrStart | AnimationMode::ReplaceOrSnap => {
-                        *to = animation_target.target.clone();
-                        *to_i = tween(&animation_target.target, &animation_target.target, 1.0);
-                        let last_animation = animations.front_mut().unwrap();
-                        last_animation.to = animation_target.target;
-                        last_animation.to_i = to_i.clone();
-                    }
                     AnimationMode::Snap => {
+                        hooks.update_value(|hooks| hooks.cancelled = true);
                         *animation_status = AnimationStatus::Snap(animation_target.target)
                     }
                 },
             }
         });
+        status_changed.set(());
         context.request_animation_frame();
     });
 
@@ -479,9 +2279,24 @@ where
         let was_snap = animation_status
             .with_value(|animation_status| matches!(animation_status, AnimationStatus::Snap(_)));
 
+        let finish = context.motion_policy_untracked() == MotionPolicy::None
+            || context.finishing.get_value();
         animation_status.update_value(|animation_status| {
-            animation_status.remove_finished_animations();
+            // Finishing goes through the Snap status so that the signal updates
+            if finish && matches!(animation_status, AnimationStatus::Running { .. }) {
+                animation_status.finish();
+            } else {
+                animation_status.remove_finished_animations(now());
+            }
+        });
+
+        // Keep this signal updated in the animation loop, unless it is paused
+        let is_running = animation_status.with_value(|animation_status| {
+            matches!(animation_status, AnimationStatus::Running { .. })
         });
+        if is_running && paused_at.with_value(Option::is_none) {
+            context.request_animation_frame();
+        }
 
         if was_snap {
             SignalUpdate::Update
@@ -493,33 +2308,64 @@ where
         }
     });
 
-    let animated_signal = Signal::derive(move || {
+    let is_running = create_memo(move |_| {
+        status_changed.track();
         animation_tick.track();
+        animation_status.with_value(|animation_status| {
+            matches!(animation_status, AnimationStatus::Running { .. })
+        })
+    });
+
+    // Keep track of the number of running animated signals in the context and notify hooks
+    let running_effect = create_isomorphic_effect(move |was_running| {
+        let running = is_running.get();
+        if running != was_running.unwrap_or(false) {
+            context
+                .active_count
+                .update(|count| if running { *count += 1 } else { *count -= 1 });
+            if running {
+                run_start_hooks(hooks);
+            } else {
+                run_stop_hooks(hooks);
+            }
+        }
+        running
+    });
+    on_cleanup(move || release(context, is_running, hooks));
+
+    let animated_signal = Signal::derive(move || {
+        let tween = tween.get_value();
+
+        // Nothing animates on the server, so render the target directly
+        if context.is_server() {
+            let target = source.get().target;
+            return tween(&target, &target, 1.0);
+        }
 
+        animation_tick.track();
         let i: I = animation_status.with_value(|animation_status| match animation_status {
             AnimationStatus::Static(state) | AnimationStatus::Snap(state) => {
                 tween(state, state, 1.0)
             }
             AnimationStatus::Running {
                 animations, to_i, ..
-            } => {
-                // Keep this signal updated in the animation loop
-                context.request_animation_frame();
-
-                // Add all animation results to a single value
-                animations.iter().fold(to_i.clone(), |acc, animation| {
-                    let animation_value =
-                        tween(&animation.from, &animation.to, animation.progress());
-
-                    acc - (animation.to_i.clone() - animation_value)
-                })
-            }
+            } => fold_animations(animations, to_i, now(), &*tween, &*compose),
         });
         i
     });
 
     AnimatedSignal {
+        context,
+        source,
+        tween,
         animation_status,
+        paused_at,
+        status_changed,
+        is_running,
+        hooks,
+        stack_limit,
+        starts_from_current_value: to_target.is_some(),
+        running_effect,
         update_animation_status_effect,
         animation_tick,
         animated_signal,
@@ -537,14 +2383,337 @@ where
     (*to - *from) * progress + *from
 }
 
+/// A signal that is an animated version of its source signal, created with [`create_animated_signal()`].
+/// It dereferences to a [`Signal`] with the animated value.
+///
+/// It also has playback controls for its running animations:
+///
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animated_signal, easing, tween_default, AnimationContext};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// let (value, set_value) = create_signal(0.0);
+/// let animated_value = create_animated_signal(
+///     move || (value.get(), Duration::from_secs(1), easing::LINEAR).into(),
+///     tween_default::<f64, f64>,
+/// );
+///
+/// set_value.set(100.0);
+/// clock.advance(Duration::from_millis(200));
+/// animated_value.pause();
+/// clock.advance(Duration::from_secs(10));
+/// animation_frame();
+/// assert_eq!(animated_value.get_untracked(), 20.0);
+///
+/// animated_value.seek(0.5);
+/// animation_frame();
+/// assert_eq!(animated_value.get_untracked(), 50.0);
+///
+/// animated_value.resume();
+/// animated_value.reverse();
+/// clock.advance(Duration::from_millis(250));
+/// animation_frame();
+/// assert_eq!(animated_value.get_untracked(), 25.0);
+///
+/// // The reversed animation ends where it started
+/// clock.advance(Duration::from_millis(250));
+/// animation_frame();
+/// assert_eq!(animated_value.get_untracked(), 0.0);
+/// # runtime.dispose();
+/// ```
+///
+/// The state of the animations is available as signals, for example to disable a button while an
+/// animation is running:
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animated_signal, easing, tween_default, AnimationContext};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// let (value, set_value) = create_signal(0.0);
+/// let animated_value = create_animated_signal(
+///     move || (value.get(), Duration::from_secs(1), easing::LINEAR).into(),
+///     tween_default::<f64, f64>,
+/// );
+/// let is_running = animated_value.is_running();
+/// let progress = animated_value.progress();
+///
+/// set_value.set(100.0);
+/// assert!(is_running.get());
+/// assert_eq!(context.active_count().get(), 1);
+///
+/// clock.advance(Duration::from_millis(250));
+/// animation_frame();
+/// assert_eq!(progress.get(), 0.25);
+///
+/// clock.advance(Duration::from_secs(1));
+/// animation_frame();
+/// assert!(!is_running.get());
+/// assert_eq!(context.active_count().get(), 0);
+/// # runtime.dispose();
+/// ```
 #[derive(Copy, Clone)]
 pub struct AnimatedSignal<T: 'static, I: 'static> {
+    context: AnimationContext,
+    source: Signal<AnimationTarget<T>>,
+    tween: StoredValue<Rc<Tween<T, I>>>,
     animation_status: StoredValue<AnimationStatus<T, I>>,
+    paused_at: StoredValue<Option<Instant>>,
+    status_changed: RwSignal<()>,
+    is_running: Memo<bool>,
+    hooks: StoredValue<Hooks>,
+    stack_limit: StoredValue<StackLimit>,
+    /// Whether the signal was created with [`create_animation()`], and animations can start from its value
+    starts_from_current_value: bool,
+    running_effect: Effect<bool>,
     update_animation_status_effect: Effect<()>,
     animation_tick: Memo<SignalUpdate>,
     animated_signal: Signal<I>,
 }
 
+impl<T: Clone, I> AnimatedSignal<T, I> {
+    /// Freeze all animations of this signal at their current value. Animations that start while
+    /// the signal is paused are frozen at their start.
+    pub fn pause(&self) {
+        if self.paused_at.with_value(Option::is_none) {
+            self.paused_at.set_value(Some(self.context.now()));
+        }
+    }
+
+    /// Limit the number of animations that run on top of each other. Every change of the input signal with
+    /// [`AnimationMode::Start`] adds an animation, the `policy` decides what happens when there are more than `max`.
+    /// By default the number of animations is unlimited.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animation, easing, AnimationContext, StackPolicy};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (target, set_target) = create_signal(0.0);
+    /// let value = create_animation::<f64>(move || (target.get(), Duration::from_secs(1), easing::LINEAR).into());
+    /// value.set_stack_limit(1, StackPolicy::Merge);
+    ///
+    /// set_target.set(100.0);
+    /// clock.advance(Duration::from_millis(500));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 50.0);
+    ///
+    /// // The running animation is merged into a new one that starts from the current value
+    /// set_target.set(200.0);
+    /// assert_eq!(value.get_untracked(), 50.0);
+    /// clock.advance(Duration::from_millis(500));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 125.0);
+    /// # runtime.dispose();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// In debug builds when `policy` is [`StackPolicy::Merge`] and the signal was created with
+    /// [`create_animated_signal()`], which can't merge animations.
+    pub fn set_stack_limit(&self, max: usize, policy: StackPolicy) {
+        debug_assert!(
+            policy != StackPolicy::Merge || self.starts_from_current_value,
+            "StackPolicy::Merge needs a signal created with create_animation(), \
+             use StackPolicy::Replace or StackPolicy::Drop with create_animated_signal()"
+        );
+        self.stack_limit.set_value(StackLimit { max, policy });
+    }
+
+    /// Continue playing animations from where they were paused
+    pub fn resume(&self) {
+        if let Some(paused_at) = self.paused_at.get_value() {
+            let paused_for = self.context.now().saturating_duration_since(paused_at);
+            self.animation_status.update_value(|animation_status| {
+                if let AnimationStatus::Running { animations, .. } = animation_status {
+                    for animation in animations {
+                        animation.start += paused_for;
+                    }
+                }
+            });
+            self.paused_at.set_value(None);
+            self.context.request_animation_frame();
+        }
+    }
+
+    /// Whether the animations of this signal are paused
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.with_value(Option::is_some)
+    }
+
+    /// A signal that is `true` while this signal has animations running, including while they are paused
+    pub fn is_running(&self) -> Signal<bool> {
+        self.is_running.into()
+    }
+
+    /// Call the callback each time this signal starts running animations after having been at rest
+    pub fn on_start(&self, callback: impl Fn() + 'static) {
+        self.hooks
+            .update_value(|hooks| hooks.on_start.push(Rc::new(callback)));
+    }
+
+    /// Call the callback each time all running animations of this signal have played to the end,
+    /// either on their own or through [`finish()`](AnimatedSignal::finish)
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animated_signal, tween_default, AnimationContext, AnimationMode};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (value, set_value) = create_signal((0.0, AnimationMode::Start));
+    /// let animated_value = create_animated_signal(move || value.get().into(), tween_default::<f64, f64>);
+    ///
+    /// let (completed, set_completed) = create_signal(0);
+    /// let (cancelled, set_cancelled) = create_signal(0);
+    /// animated_value.on_complete(move || set_completed.update(|count| *count += 1));
+    /// animated_value.on_cancel(move || set_cancelled.update(|count| *count += 1));
+    ///
+    /// set_value.set((100.0, AnimationMode::Start));
+    /// clock.advance(Duration::from_secs(1));
+    /// animation_frame();
+    /// assert_eq!((completed.get(), cancelled.get()), (1, 0));
+    ///
+    /// set_value.set((0.0, AnimationMode::Start));
+    /// set_value.set((50.0, AnimationMode::Snap));
+    /// assert_eq!((completed.get(), cancelled.get()), (1, 1));
+    /// # runtime.dispose();
+    /// ```
+    pub fn on_complete(&self, callback: impl Fn() + 'static) {
+        self.hooks
+            .update_value(|hooks| hooks.on_complete.push(Rc::new(callback)));
+    }
+
+    /// Call the callback each time running animations of this signal are cut short, either by a
+    /// target with [`AnimationMode::Snap`] or because the signal is disposed
+    pub fn on_cancel(&self, callback: impl Fn() + 'static) {
+        self.hooks
+            .update_value(|hooks| hooks.on_cancel.push(Rc::new(callback)));
+    }
+
+    /// A future that resolves when this signal has no more running animations, either because they
+    /// completed or because they were cancelled. Resolves immediately when no animations are running.
+    ///
+    /// This can be used to wait for an exit animation before removing a component:
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::*;
+    /// # async fn hide(set_opacity: WriteSignal<f64>, opacity: AnimatedSignal<f64, f64>, set_visible: WriteSignal<bool>) {
+    /// set_opacity.set(0.0);
+    /// opacity.finished().await;
+    /// set_visible.set(false);
+    /// # }
+    /// ```
+    pub fn finished(&self) -> Finished {
+        let state = Rc::new(RefCell::new(FinishedState::default()));
+        if self.is_running.get_untracked() {
+            self.hooks
+                .update_value(|hooks| hooks.finished.push(state.clone()));
+        } else {
+            state.borrow_mut().finish();
+        }
+        Finished { state }
+    }
+
+    /// A signal with how far the most recently started animation has played, between 0.0 and 1.0.
+    /// The easing is not applied to this value. When no animations are running the progress is 1.0
+    pub fn progress(&self) -> Signal<f64> {
+        let AnimatedSignal {
+            context,
+            animation_status,
+            paused_at,
+            status_changed,
+            animation_tick,
+            ..
+        } = *self;
+
+        Signal::derive(move || {
+            status_changed.track();
+            animation_tick.track();
+            let now = playback_now(context, paused_at);
+            animation_status.with_value(|animation_status| match animation_status {
+                AnimationStatus::Running { animations, .. } => {
+                    animations.front().unwrap().progress(now)
+                }
+                AnimationStatus::Static(_) | AnimationStatus::Snap(_) => 1.0,
+            })
+        })
+    }
+
+    /// Jump directly to the end of all running animations
+    pub fn finish(&self) {
+        self.animation_status
+            .update_value(|animation_status| animation_status.finish());
+        self.status_changed.set(());
+        self.context.request_animation_frame();
+    }
+
+    /// Move all running animations to the given position between 0.0 (start) and 1.0 (end).
+    /// Combined with [`pause()`](AnimatedSignal::pause) this can be used to scrub through animations.
+    pub fn seek(&self, position: f64) {
+        let now = playback_now(self.context, self.paused_at);
+        self.animation_status
+            .update_value(|animation_status| animation_status.seek(now, position));
+        self.context.request_animation_frame();
+    }
+
+    /// Play all running animations backwards from their current position. The signal then animates
+    /// back to the value it had before the animations started. Reversing again plays the
+    /// animations forward again.
+    pub fn reverse(&self) {
+        let now = playback_now(self.context, self.paused_at);
+        let tween = self.tween.get_value();
+        self.animation_status
+            .update_value(|animation_status| animation_status.reverse(now, &*tween));
+        self.context.request_animation_frame();
+    }
+}
+
+#[derive(Default)]
+struct FinishedState {
+    finished: bool,
+    waker: Option<Waker>,
+}
+
+impl FinishedState {
+    fn finish(&mut self) {
+        self.finished = true;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Future returned by [`AnimatedSignal::finished()`]
+pub struct Finished {
+    state: Rc<RefCell<FinishedState>>,
+}
+
+impl Future for Finished {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+        if state.finished {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
 impl<T, I> Deref for AnimatedSignal<T, I> {
     type Target = Signal<I>;
 
@@ -555,7 +2724,16 @@ impl<T, I> Deref for AnimatedSignal<T, I> {
 
 impl<T, I> SignalDispose for AnimatedSignal<T, I> {
     fn dispose(self) {
+        release(self.context, self.is_running, self.hooks);
+        self.source.dispose();
+        self.tween.dispose();
         self.animation_status.dispose();
+        self.paused_at.dispose();
+        self.stack_limit.dispose();
+        self.status_changed.dispose();
+        self.hooks.dispose();
+        self.running_effect.dispose();
+        self.is_running.dispose();
         self.animation_tick.dispose();
         self.update_animation_status_effect.dispose();
         self.animated_signal.dispose();
@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+/// Distance to the target below which a spring is considered to be at rest
+const REST_THRESHOLD: f64 = 0.001;
+
+/// Springs that never come to rest (such as springs without damping) are cut off after this time
+const MAX_SETTLING_TIME: f64 = 60.0;
+
+/// Newton steps taken to find the moment a spring comes to rest
+const SETTLING_ITERATIONS: usize = 4;
+
+/// The lowest stiffness and mass of a spring, lower values are raised to this
+const MIN_STIFFNESS_AND_MASS: f64 = 0.001;
+
+/// A `Spring` describes a physics based animation as an alternative to a fixed duration and easing.
+/// The animation ends when the spring has come to rest.
+///
+/// Set it on the [`spring`](crate::AnimationTarget::spring) field of an [`AnimationTarget`](crate::AnimationTarget)
+/// or create a target from a tuple:
+/// ```
+/// # use leptos_animation::{spring::Spring, AnimationTarget};
+/// let _: AnimationTarget<f64> = (42.0, Spring::new(300.0, 10.0, 1.0)).into();
+/// ```
+///
+/// Because animations are additive a spring that is started while another animation is still
+/// running starts from rest on top of it. The velocity of the animated value is therefore preserved
+/// when the target changes mid-flight with [`AnimationMode::Start`](crate::AnimationMode::Start).
+///
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, create_animated_signal, spring::Spring, tween_default, AnimationContext};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// let (value, set_value) = create_signal(0.0);
+/// let animated_value = create_animated_signal(
+///     move || (value.get(), Spring::WOBBLY).into(),
+///     tween_default::<f64, f64>,
+/// );
+///
+/// set_value.set(100.0);
+/// clock.advance(Duration::from_millis(200));
+/// animation_frame();
+/// // A wobbly spring overshoots its target
+/// assert!(animated_value.get_untracked() > 100.0);
+///
+/// clock.advance(Spring::WOBBLY.settling_duration());
+/// animation_frame();
+/// assert_eq!(animated_value.get_untracked(), 100.0);
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spring {
+    /// The force with which the spring pulls towards the target. Higher is faster.
+    pub stiffness: f64,
+
+    /// The friction that slows the spring down. Lower values make the spring oscillate more.
+    /// Without damping the spring oscillates until it is cut off after a minute.
+    pub damping: f64,
+
+    /// The mass of the animated object. Higher values make the spring slower and oscillate more.
+    pub mass: f64,
+}
+
+impl Spring {
+    /// A spring that gently settles without much oscillation
+    pub const GENTLE: Spring = Spring::new(120.0, 14.0, 1.0);
+
+    /// A spring that noticeably overshoots its target a few times
+    pub const WOBBLY: Spring = Spring::new(180.0, 12.0, 1.0);
+
+    /// A spring that quickly settles on its target
+    pub const STIFF: Spring = Spring::new(210.0, 20.0, 1.0);
+
+    /// Create a spring. The stiffness and mass must be positive and the damping can't be negative,
+    /// other values (including NaN) are raised to the lowest allowed value so that the spring
+    /// always comes to rest.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos_animation::spring::Spring;
+    /// let spring = Spring::new(0.0, -10.0, f64::NAN);
+    /// assert_eq!(spring, Spring::new(0.001, 0.0, 0.001));
+    /// assert!(spring.progress(0.5, 0.0).is_finite());
+    /// assert!(spring.settling_duration() <= Duration::from_secs(60));
+    /// ```
+    pub const fn new(stiffness: f64, damping: f64, mass: f64) -> Spring {
+        Spring {
+            stiffness,
+            damping,
+            mass,
+        }
+        .clamped()
+    }
+
+    /// The spring with its parameters raised to the lowest allowed values, for springs whose
+    /// fields are set directly
+    const fn clamped(self) -> Spring {
+        // Written so that NaN is raised as well
+        const fn at_least(value: f64, min: f64) -> f64 {
+            if value >= min {
+                value
+            } else {
+                min
+            }
+        }
+        Spring {
+            stiffness: at_least(self.stiffness, MIN_STIFFNESS_AND_MASS),
+            damping: at_least(self.damping, 0.0),
+            mass: at_least(self.mass, MIN_STIFFNESS_AND_MASS),
+        }
+    }
+
+    /// The progress of an animation driven by this spring after `time` seconds. The progress starts
+    /// at 0.0 with the given velocity (in progress per second) and comes to rest at 1.0.
+    pub fn progress(&self, time: f64, velocity: f64) -> f64 {
+        1.0 + self.displacement(time, -1.0, velocity).0
+    }
+
+    /// The position after `time` seconds of a spring that starts at its rest point with a velocity of 1.0
+    pub(crate) fn impulse(&self, time: f64) -> f64 {
+        self.displacement(time, 0.0, 1.0).0
+    }
+
+    /// The time it takes for a spring that starts at rest to come to rest at its target. From then
+    /// on the distance to the target, where the whole animation is a distance of 1.0, stays below
+    /// a thousandth, also when the spring still oscillates.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos_animation::spring::Spring;
+    /// for spring in [Spring::GENTLE, Spring::WOBBLY, Spring::STIFF, Spring::new(100.0, 40.0, 1.0)] {
+    ///     let settled = spring.settling_duration().as_secs_f64();
+    ///     for i in 0..100 {
+    ///         let time = settled + i as f64 / 100.0;
+    ///         assert!((1.0 - spring.progress(time, 0.0)).abs() < 0.001);
+    ///     }
+    /// }
+    /// assert_eq!(Spring::new(100.0, 0.0, 1.0).settling_duration(), Duration::from_secs(60));
+    /// ```
+    pub fn settling_duration(&self) -> Duration {
+        let Spring {
+            stiffness,
+            damping,
+            mass,
+        } = self.clamped();
+        let omega = (stiffness / mass).sqrt();
+        let zeta = damping / (2.0 * (stiffness * mass).sqrt());
+
+        // The distance to the target is at most `(1 + min(zeta * omega * t, limit)) * exp(-decay * t)`,
+        // see `displacement()`
+        let (decay, limit) = if zeta < 1.0 {
+            (zeta * omega, zeta / (1.0 - zeta * zeta).sqrt())
+        } else if zeta == 1.0 {
+            (omega, f64::INFINITY)
+        } else {
+            let root = omega * (zeta * zeta - 1.0).sqrt();
+            (zeta * omega - root, zeta * omega / (2.0 * root))
+        };
+        if decay <= 0.0 {
+            return Duration::from_secs_f64(MAX_SETTLING_TIME);
+        }
+
+        // Solve `ln(bound) = ln(REST_THRESHOLD)` with Newton's method, starting where the decay
+        // alone reaches the threshold. The logarithm of the bound is concave, so after the first
+        // step every step lands at or after the moment the bound reaches the threshold.
+        let mut time = -REST_THRESHOLD.ln() / decay;
+        for _ in 0..SETTLING_ITERATIONS {
+            let growth = zeta * omega * time;
+            let value = (1.0 + growth.min(limit)).ln() - decay * time - REST_THRESHOLD.ln();
+            let slope = if growth < limit {
+                zeta * omega / (1.0 + growth)
+            } else {
+                0.0
+            } - decay;
+            time -= value / slope;
+        }
+        Duration::from_secs_f64(time.min(MAX_SETTLING_TIME))
+    }
+
+    /// Analytic solution of a damped harmonic oscillator. Returns the position and velocity relative
+    /// to the rest point after `time` seconds, starting from the given `position` and `velocity`.
+    fn displacement(&self, time: f64, position: f64, velocity: f64) -> (f64, f64) {
+        let Spring {
+            stiffness,
+            damping,
+            mass,
+        } = self.clamped();
+        let omega = (stiffness / mass).sqrt();
+        let zeta = damping / (2.0 * (stiffness * mass).sqrt());
+
+        if zeta < 1.0 {
+            // Underdamped, oscillates around the rest point
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let decay = (-zeta * omega * time).exp();
+            let a = position;
+            let b = (velocity + zeta * omega * position) / omega_d;
+            let (sin, cos) = (omega_d * time).sin_cos();
+            (
+                decay * (a * cos + b * sin),
+                decay
+                    * ((b * omega_d - zeta * omega * a) * cos
+                        - (a * omega_d + zeta * omega * b) * sin),
+            )
+        } else if zeta == 1.0 {
+            // Critically damped, the fastest way to the rest point without overshooting
+            let decay = (-omega * time).exp();
+            let b = velocity + omega * position;
+            (
+                decay * (position + b * time),
+                decay * (b - omega * (position + b * time)),
+            )
+        } else {
+            // Overdamped, slowly creeps towards the rest point
+            let root = omega * (zeta * zeta - 1.0).sqrt();
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+            let c2 = (velocity - r1 * position) / (r2 - r1);
+            let c1 = position - c2;
+            let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        }
+    }
+}
+
+impl Default for Spring {
+    /// A spring with a stiffness of 170, damping of 26 and a mass of 1
+    fn default() -> Self {
+        Spring::new(170.0, 26.0, 1.0)
+    }
+}
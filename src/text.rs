@@ -0,0 +1,564 @@
+//! Animated text.
+//!
+//! A [`Text`] is animated with [`create_animation()`](crate::create_animation) and changes into
+//! the next text with the transition of that next text:
+//!
+//! * [`Text::typewriter()`] deletes the old text back to the part both texts have in common and
+//!   then types the new text
+//! * [`Text::scramble()`] decodes the new text from left to right out of random characters
+//! * [`Text::morph()`] changes the old text into the new text one character edit at a time
+//!
+//! [`count()`] is a tween function for [`create_animated_signal()`](crate::create_animated_signal)
+//! that counts the first number in a text, keeping the formatting of the new text.
+//!
+//! # Overlapping animations
+//!
+//! Animations are additive, see [`create_animated_signal()`](crate::create_animated_signal). There
+//! is no meaningful way to add texts together, so the difference between texts is a [`TextDelta`]
+//! that replaces a text and the most recently started animation wins: it continues from the text
+//! that is shown when it starts, and once it is done it shows its target even when older
+//! animations are still running. [`count()`] returns a [`Count`] which adds up numbers like any
+//! other numeric animation, so counting towards a new number while still counting smoothly
+//! changes course.
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use leptos::*;
+//! # use leptos_animation::{clock::ManualClock, create_animation, easing, text::Text, AnimationContext};
+//! # let runtime = create_runtime();
+//! # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+//! # let clock = ManualClock::new();
+//! # context.set_clock(clock.clone());
+//! let (greeting, set_greeting) = create_signal("");
+//! let animated_greeting = create_animation::<Text>(move || {
+//!     // Type 10 characters per second
+//!     let greeting = greeting.get();
+//!     let duration = Duration::from_millis(100) * greeting.len() as u32;
+//!     (Text::typewriter(greeting), duration, easing::LINEAR).into()
+//! });
+//!
+//! set_greeting.set("Hello World");
+//! clock.advance(Duration::from_millis(550));
+//! animation_frame();
+//! assert_eq!(animated_greeting.get_untracked().as_str(), "Hello");
+//!
+//! // The new animation deletes the text that is shown, while the old animation keeps typing
+//! set_greeting.set("Bye");
+//! assert_eq!(animated_greeting.get_untracked().as_str(), "Hello");
+//! clock.advance(Duration::from_millis(150));
+//! animation_frame();
+//! assert_eq!(animated_greeting.get_untracked().as_str(), "He");
+//!
+//! // Done before the old animation
+//! clock.advance(Duration::from_millis(200));
+//! animation_frame();
+//! assert_eq!(animated_greeting.get_untracked().as_str(), "Bye");
+//! # runtime.dispose();
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Deref, Sub};
+use std::rc::Rc;
+
+use crate::Animatable;
+
+/// The characters that [`Text::scramble()`] shows before a character is decoded
+pub const SCRAMBLE_GLYPHS: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!@#$%&*<>?/";
+
+/// The number of times per animation that the random characters of [`Text::scramble()`] change
+const SCRAMBLE_FRAMES: f64 = 100.0;
+
+/// An animated text, together with the transition that animates towards it. When animations
+/// overlap the most recently started animation wins, see
+/// [Overlapping animations](self#overlapping-animations).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Text {
+    text: String,
+    transition: TextTransition,
+}
+
+/// How a [`Text`] changes from the text before it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TextTransition {
+    /// See [`Text::typewriter()`]
+    Typewriter,
+
+    /// See [`Text::scramble()`], with the random characters to decode from
+    Scramble(Rc<str>),
+
+    /// See [`Text::morph()`]
+    Morph,
+}
+
+impl TextTransition {
+    fn apply(&self, from: &str, to: &str, progress: f64) -> String {
+        match self {
+            TextTransition::Typewriter => typewriter_text(from, to, progress),
+            TextTransition::Scramble(glyphs) => scramble_text(from, to, progress, glyphs),
+            TextTransition::Morph => morph_text(from, to, progress),
+        }
+    }
+}
+
+impl Text {
+    pub fn new(text: impl Into<String>, transition: TextTransition) -> Text {
+        Text {
+            text: text.into(),
+            transition,
+        }
+    }
+
+    /// Delete the old text back to the part that both texts start with, then type the new text.
+    /// Every deleted or typed character takes the same amount of time.
+    ///
+    /// ```
+    /// # use leptos_animation::{text::Text, Animatable};
+    /// let from = Text::typewriter("Hello World");
+    /// let to = Text::typewriter("Hello There");
+    /// assert_eq!(from.interpolate(&to, 0.3).as_str(), "Hello Wo");
+    /// assert_eq!(from.interpolate(&to, 0.7).as_str(), "Hello Th");
+    /// ```
+    pub fn typewriter(text: impl Into<String>) -> Text {
+        Text::new(text, TextTransition::Typewriter)
+    }
+
+    /// Decode the new text from left to right out of random characters from [`SCRAMBLE_GLYPHS`].
+    /// The length of the text changes gradually from the old length to the new length.
+    /// Whitespace in the new text is kept so that the shape of the words is visible while
+    /// decoding.
+    ///
+    /// The random characters are chosen deterministically from the progress of the animation.
+    ///
+    /// ```
+    /// # use leptos_animation::{text::Text, Animatable};
+    /// let halfway = Text::scramble("").interpolate(&Text::scramble("Hello World"), 0.5);
+    /// assert_eq!(halfway.chars().count(), 6);
+    /// assert!(halfway.starts_with("Hello"));
+    /// ```
+    pub fn scramble(text: impl Into<String>) -> Text {
+        Text::scramble_with(text, SCRAMBLE_GLYPHS)
+    }
+
+    /// [`Text::scramble()`] with custom random characters
+    ///
+    /// ```
+    /// # use leptos_animation::{text::Text, Animatable};
+    /// let binary = Text::scramble("").interpolate(&Text::scramble_with("ABCD", "01"), 0.5);
+    /// assert_eq!(binary.get(..2), Some("AB"));
+    /// ```
+    pub fn scramble_with(text: impl Into<String>, glyphs: &str) -> Text {
+        Text::new(text, TextTransition::Scramble(glyphs.into()))
+    }
+
+    /// Change the old text into the new text with the smallest number of character insertions,
+    /// deletions and substitutions, applied from left to right.
+    ///
+    /// Finding the smallest number of changes takes time proportional to the product of the
+    /// lengths of the parts of both texts that differ, so this is best suited for shorter texts.
+    ///
+    /// ```
+    /// # use leptos_animation::{text::Text, Animatable};
+    /// let from = Text::morph("kitten");
+    /// let to = Text::morph("sitting");
+    /// assert_eq!(from.interpolate(&to, 0.4).as_str(), "sitten");
+    /// assert_eq!(from.interpolate(&to, 0.7).as_str(), "sittin");
+    /// ```
+    pub fn morph(text: impl Into<String>) -> Text {
+        Text::new(text, TextTransition::Morph)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn transition(&self) -> &TextTransition {
+        &self.transition
+    }
+}
+
+impl Deref for Text {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for Text {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<Text> for String {
+    fn from(text: Text) -> Self {
+        text.text
+    }
+}
+
+/// The difference between two texts. Adding it to a text replaces that text, see
+/// [Overlapping animations](self#overlapping-animations).
+///
+/// ```
+/// # use leptos_animation::{text::Text, Animatable};
+/// let shown = Text::typewriter("Hello");
+/// let delta = Text::typewriter("").interpolate_delta(&Text::typewriter("Bye"), 0.5, &shown);
+/// assert_eq!(shown.add_delta(&delta).as_str(), "B");
+///
+/// // Added after an older animation it continues from the text of that animation
+/// let older = Text::typewriter("").interpolate_delta(&Text::typewriter("Hello"), 1.0, &shown);
+/// assert_eq!(shown.add_deltas(&[&older, &delta]).as_str(), "H");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextDelta {
+    /// The texts are the same, adding this difference leaves a text unchanged
+    Keep,
+
+    /// Adding this difference replaces a text
+    Replace(Text),
+
+    /// The difference between an animation at the given progress and any other text. Adding it
+    /// replaces a text with the text of the animation. When several differences are added at
+    /// once the animation continues from the text of the differences before it instead.
+    Transition { from: Text, to: Text, progress: f64 },
+}
+
+impl Animatable for Text {
+    type Delta = TextDelta;
+
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        if progress <= 0.0 {
+            return self.clone();
+        } else if progress >= 1.0 {
+            return to.clone();
+        }
+        Text {
+            text: to.transition.apply(&self.text, &to.text, progress),
+            transition: to.transition.clone(),
+        }
+    }
+
+    fn delta(&self, other: &Self) -> Self::Delta {
+        if self == other {
+            TextDelta::Keep
+        } else {
+            TextDelta::Replace(self.clone())
+        }
+    }
+
+    fn add_delta(&self, delta: &Self::Delta) -> Self {
+        match delta {
+            TextDelta::Keep => self.clone(),
+            TextDelta::Replace(text) => text.clone(),
+            TextDelta::Transition { from, to, progress } => from.interpolate(to, *progress),
+        }
+    }
+
+    fn zero_delta() -> Self::Delta {
+        TextDelta::Keep
+    }
+
+    fn interpolate_delta(&self, to: &Self, progress: f64, _: &Self) -> Self::Delta {
+        TextDelta::Transition {
+            from: self.clone(),
+            to: to.clone(),
+            progress,
+        }
+    }
+
+    fn add_deltas(&self, deltas: &[&Self::Delta]) -> Self {
+        // Each animation continues from the text that the older animations show, so the newest
+        // one wins
+        let mut shown: Option<Text> = None;
+        for delta in deltas {
+            shown = match (delta, shown) {
+                (TextDelta::Keep, shown) => shown,
+                (TextDelta::Transition { to, progress, .. }, Some(shown)) => {
+                    Some(shown.interpolate(to, *progress))
+                }
+                (delta, _) => Some(self.add_delta(delta)),
+            };
+        }
+        shown.unwrap_or_else(|| self.clone())
+    }
+}
+
+fn typewriter_text(from: &str, to: &str, progress: f64) -> String {
+    let from: Vec<char> = from.chars().collect();
+    let to: Vec<char> = to.chars().collect();
+    let common = common_prefix(&from, &to);
+
+    let deletions = from.len() - common;
+    let insertions = to.len() - common;
+    let steps = steps(deletions + insertions, progress);
+
+    let text = if steps <= deletions {
+        &from[..from.len() - steps]
+    } else {
+        &to[..common + steps - deletions]
+    };
+    String::from_iter(text)
+}
+
+fn scramble_text(from: &str, to: &str, progress: f64, glyphs: &str) -> String {
+    if progress <= 0.0 {
+        return from.to_string();
+    }
+    let glyphs: Vec<char> = glyphs.chars().collect();
+    let from_length = from.chars().count();
+    let to: Vec<char> = to.chars().collect();
+
+    let progress = progress.min(1.0);
+    let length = (from_length as f64 + (to.len() as f64 - from_length as f64) * progress).round();
+    let decoded = steps(to.len(), progress);
+    let frame = (progress * SCRAMBLE_FRAMES) as u64;
+
+    (0..length as usize)
+        .map(|i| match to.get(i) {
+            Some(&c) if i < decoded || c.is_whitespace() => c,
+            _ if glyphs.is_empty() => ' ',
+            _ => glyphs[random(i as u64, frame) as usize % glyphs.len()],
+        })
+        .collect()
+}
+
+fn morph_text(from: &str, to: &str, progress: f64) -> String {
+    let from: Vec<char> = from.chars().collect();
+    let to: Vec<char> = to.chars().collect();
+
+    // Only align the part in between the common start and end
+    let prefix = common_prefix(&from, &to);
+    let suffix = common_suffix(&from[prefix..], &to[prefix..]);
+    let edits = edit_script(
+        &from[prefix..from.len() - suffix],
+        &to[prefix..to.len() - suffix],
+    );
+
+    let changes = edits
+        .iter()
+        .filter(|edit| !matches!(edit, Edit::Keep(_)))
+        .count();
+    let mut applied = steps(changes, progress);
+
+    let mut text = String::from_iter(&from[..prefix]);
+    for edit in edits {
+        let apply = !matches!(edit, Edit::Keep(_)) && applied > 0;
+        if apply {
+            applied -= 1;
+        }
+        match (edit, apply) {
+            (Edit::Keep(c), _)
+            | (Edit::Substitute(c, _), false)
+            | (Edit::Delete(c), false)
+            | (Edit::Substitute(_, c), true)
+            | (Edit::Insert(c), true) => text.push(c),
+            (Edit::Delete(_), true) | (Edit::Insert(_), false) => {}
+        }
+    }
+    text.extend(&from[from.len() - suffix..]);
+    text
+}
+
+enum Edit {
+    Keep(char),
+    Substitute(char, char),
+    Delete(char),
+    Insert(char),
+}
+
+/// The Levenshtein edit script that turns `from` into `to`
+fn edit_script(from: &[char], to: &[char]) -> Vec<Edit> {
+    // distances[i][j] is the edit distance between from[i..] and to[j..]
+    let mut distances = vec![vec![0; to.len() + 1]; from.len() + 1];
+    for i in (0..=from.len()).rev() {
+        for j in (0..=to.len()).rev() {
+            distances[i][j] = if i == from.len() {
+                to.len() - j
+            } else if j == to.len() {
+                from.len() - i
+            } else if from[i] == to[j] {
+                distances[i + 1][j + 1]
+            } else {
+                1 + distances[i + 1][j + 1]
+                    .min(distances[i + 1][j])
+                    .min(distances[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut edits = vec![];
+    while i < from.len() || j < to.len() {
+        if i < from.len() && j < to.len() && from[i] == to[j] {
+            edits.push(Edit::Keep(from[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < from.len() && j < to.len() && distances[i][j] == distances[i + 1][j + 1] + 1 {
+            edits.push(Edit::Substitute(from[i], to[j]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < from.len() && distances[i][j] == distances[i + 1][j] + 1 {
+            edits.push(Edit::Delete(from[i]));
+            i += 1;
+        } else {
+            edits.push(Edit::Insert(to[j]));
+            j += 1;
+        }
+    }
+    edits
+}
+
+/// An animated number inside a text, created by [`count()`]. Overlapping animations are added
+/// together like numbers. It is displayed with the formatting of the text it animates towards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Count {
+    value: f64,
+    format: Rc<NumberFormat>,
+}
+
+#[derive(Debug, PartialEq)]
+struct NumberFormat {
+    /// The text before the number, or the whole text if it does not contain a number
+    prefix: String,
+    suffix: String,
+    has_number: bool,
+    decimals: usize,
+    grouped: bool,
+}
+
+impl Count {
+    /// The current value of the number
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl Display for Count {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let format = &self.format;
+        f.write_str(&format.prefix)?;
+        if format.has_number {
+            let number = format!("{:.*}", format.decimals, self.value.abs());
+            let (integer, fraction) = number.split_at(number.find('.').unwrap_or(number.len()));
+            if number.chars().any(|c| c.is_ascii_digit() && c != '0') && self.value < 0.0 {
+                f.write_str("-")?;
+            }
+            for (i, digit) in integer.chars().enumerate() {
+                if format.grouped && i > 0 && (integer.len() - i) % 3 == 0 {
+                    f.write_str(",")?;
+                }
+                write!(f, "{digit}")?;
+            }
+            f.write_str(fraction)?;
+        }
+        f.write_str(&format.suffix)
+    }
+}
+
+/// The difference between the numbers, with the formatting of `self`
+impl Sub for Count {
+    type Output = Count;
+
+    fn sub(self, rhs: Count) -> Count {
+        Count {
+            value: self.value - rhs.value,
+            format: self.format,
+        }
+    }
+}
+
+/// Count from the first number in the old text to the first number in the new text. The number is
+/// displayed with the text around it, the number of decimals and the `,` thousands separators of the
+/// new text. A text without a number counts as zero.
+///
+/// ```
+/// # use leptos_animation::text::count;
+/// assert_eq!(count(&"$0", &"$1,234.50 total", 0.5).to_string(), "$617.25 total");
+/// assert_eq!(count(&"$0", &"$1,234.50 total", 1.0).to_string(), "$1,234.50 total");
+/// ```
+pub fn count<S: AsRef<str>>(from: &S, to: &S, progress: f64) -> Count {
+    let (from, _) = parse_number(from.as_ref());
+    let (to, format) = parse_number(to.as_ref());
+    Count {
+        value: from + (to - from) * progress,
+        format: Rc::new(format),
+    }
+}
+
+/// Find the first number in a text
+fn parse_number(text: &str) -> (f64, NumberFormat) {
+    let not_found = || {
+        let format = NumberFormat {
+            prefix: text.to_string(),
+            suffix: String::new(),
+            has_number: false,
+            decimals: 0,
+            grouped: false,
+        };
+        (0.0, format)
+    };
+    let Some(mut start) = text.find(|c: char| c.is_ascii_digit()) else {
+        return not_found();
+    };
+    if text[..start].ends_with('.') {
+        start -= 1;
+    }
+    if text[..start].ends_with('-') {
+        start -= 1;
+    }
+
+    let bytes = text.as_bytes();
+    let digit_at = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+    let mut end = start;
+    let mut decimal_point = None;
+    let mut grouped = false;
+    while end < bytes.len() {
+        match bytes[end] {
+            b'-' if end == start => {}
+            b'0'..=b'9' => {}
+            b',' if decimal_point.is_none() && digit_at(end + 1) && end > start => grouped = true,
+            b'.' if decimal_point.is_none() && digit_at(end + 1) => decimal_point = Some(end),
+            _ => break,
+        }
+        end += 1;
+    }
+
+    let Ok(value) = text[start..end].replace(',', "").parse() else {
+        return not_found();
+    };
+    let format = NumberFormat {
+        prefix: text[..start].to_string(),
+        suffix: text[end..].to_string(),
+        has_number: true,
+        decimals: decimal_point.map_or(0, |point| end - point - 1),
+        grouped,
+    };
+    (value, format)
+}
+
+/// The number of discrete steps that have been taken at the given progress
+fn steps(total: usize, progress: f64) -> usize {
+    ((total as f64 * progress).floor().max(0.0) as usize).min(total)
+}
+
+fn common_prefix(a: &[char], b: &[char]) -> usize {
+    a.iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+fn common_suffix(a: &[char], b: &[char]) -> usize {
+    a.iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+/// A pseudo random number from an index and a frame (SplitMix64)
+fn random(index: u64, frame: u64) -> u64 {
+    let mut x = index
+        .wrapping_mul(0x9E3779B97F4A7C15)
+        .wrapping_add(frame)
+        .wrapping_add(0x9E3779B97F4A7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+    x ^ (x >> 31)
+}
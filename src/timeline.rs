@@ -0,0 +1,334 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use instant::Instant;
+use leptos::{
+    create_isomorphic_effect, store_value, Effect, SignalDispose, SignalSet, StoredValue,
+    WriteSignal,
+};
+
+use crate::{AnimationContext, AnimationError, AnimationTarget};
+
+struct Step {
+    offset: Duration,
+    action: Rc<dyn Fn()>,
+}
+
+#[derive(Default)]
+struct TimelineState {
+    /// All steps sorted by their offset
+    steps: Vec<Step>,
+
+    /// The offset of the previous step, used by [`Timeline::with()`]
+    previous_offset: Duration,
+
+    /// The moment the last step ends
+    end: Duration,
+
+    /// Set when the timeline is playing
+    start: Option<Instant>,
+
+    /// The index of the first step that has not run yet
+    next_step: usize,
+}
+
+impl TimelineState {
+    fn add_step(&mut self, offset: Duration, duration: Duration, action: Rc<dyn Fn()>) {
+        let index = self.steps.partition_point(|step| step.offset <= offset);
+        self.steps.insert(index, Step { offset, action });
+        self.previous_offset = offset;
+        self.end = self.end.max(offset + duration);
+    }
+
+    /// Returns the actions that are due at the given time
+    fn due_actions(&mut self, now: Instant) -> Vec<Rc<dyn Fn()>> {
+        let Some(start) = self.start else {
+            return vec![];
+        };
+        let elapsed = now.saturating_duration_since(start);
+
+        let mut actions = vec![];
+        while let Some(step) = self.steps.get(self.next_step) {
+            if step.offset > elapsed {
+                break;
+            }
+            actions.push(step.action.clone());
+            self.next_step += 1;
+        }
+
+        if elapsed >= self.end && self.next_step == self.steps.len() {
+            self.start = None;
+        }
+        actions
+    }
+}
+
+/// A `Timeline` choreographs multiple animations by running actions at set moments. Actions
+/// typically set the source signals of animated signals, which then animate towards their new targets.
+///
+/// The timeline is built up out of steps, each with a duration that is normally the duration of the
+/// animation that the step starts:
+/// * [`then()`](Timeline::then) starts a step after all previous steps have ended
+/// * [`with()`](Timeline::with) starts a step at the same moment as the previous step
+/// * [`delay()`](Timeline::delay) waits before starting the next step
+/// * [`stagger()`](Timeline::stagger) starts a list of steps one after the other with a fixed interval
+///
+/// [`then_target()`](Timeline::then_target) and [`with_target()`](Timeline::with_target) add a step
+/// that sets a target on the source signal of an animated signal and lasts as long as its animation.
+///
+/// A timeline runs on the same animation frames as all animated signals in the [`AnimationContext`].
+///
+/// ```
+/// # use std::time::Duration;
+/// # use leptos::*;
+/// # use leptos_animation::{clock::ManualClock, timeline::Timeline, AnimationContext};
+/// # let runtime = create_runtime();
+/// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+/// # let clock = ManualClock::new();
+/// # context.set_clock(clock.clone());
+/// let (title, set_title) = create_signal(0.0);
+/// let (subtitle, set_subtitle) = create_signal(0.0);
+/// let (items, set_items) = create_signal([0.0; 3]);
+///
+/// let timeline = Timeline::new()
+///     .then(Duration::from_millis(500), move || set_title.set(1.0))
+///     .with(Duration::from_millis(500), move || set_subtitle.set(1.0))
+///     .delay(Duration::from_millis(100))
+///     .stagger(
+///         Duration::from_millis(100),
+///         Duration::from_millis(500),
+///         (0..3).map(|i| move || set_items.update(|items| items[i] = 1.0)),
+///     );
+/// assert_eq!(timeline.duration(), Duration::from_millis(1300));
+///
+/// timeline.play();
+/// assert_eq!((title.get(), subtitle.get()), (1.0, 1.0));
+///
+/// clock.advance(Duration::from_millis(700));
+/// animation_frame();
+/// assert_eq!(items.get(), [1.0, 1.0, 0.0]);
+///
+/// clock.advance(Duration::from_millis(600));
+/// animation_frame();
+/// assert_eq!(items.get(), [1.0, 1.0, 1.0]);
+/// assert!(!timeline.is_playing());
+/// # runtime.dispose();
+/// ```
+#[derive(Copy, Clone)]
+pub struct Timeline {
+    context: AnimationContext,
+    state: StoredValue<TimelineState>,
+    update_effect: Effect<()>,
+}
+
+impl Timeline {
+    /// Create a new empty timeline. Requires an [`AnimationContext`] in a parent scope.
+    ///
+    /// Panics when there is no [`AnimationContext`] in a parent scope, see [`try_new()`](Timeline::try_new).
+    // No `Default` implementation because that is not expected to panic
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Timeline {
+        Timeline::with_context(AnimationContext::expect_use())
+    }
+
+    /// Like [`new()`](Timeline::new) but returns an error instead of panicking when there is no
+    /// [`AnimationContext`] in a parent scope.
+    /// ```
+    /// # use leptos::*;
+    /// # use leptos_animation::{timeline::Timeline, AnimationError};
+    /// # let runtime = create_runtime();
+    /// assert_eq!(Timeline::try_new().err(), Some(AnimationError::MissingContext));
+    /// # runtime.dispose();
+    /// ```
+    pub fn try_new() -> Result<Timeline, AnimationError> {
+        Ok(Timeline::with_context(AnimationContext::try_use()?))
+    }
+
+    fn with_context(context: AnimationContext) -> Timeline {
+        let state = store_value(TimelineState::default());
+
+        let update_effect = create_isomorphic_effect(move |_| {
+            context.animation_frame.track();
+            Timeline::run_due_actions(context, state);
+        });
+
+        Timeline {
+            context,
+            state,
+            update_effect,
+        }
+    }
+
+    /// Add a step that starts after all previous steps have ended
+    pub fn then(self, duration: Duration, action: impl Fn() + 'static) -> Timeline {
+        self.state.update_value(|state| {
+            let offset = state.end;
+            state.add_step(offset, duration, Rc::new(action))
+        });
+        self
+    }
+
+    /// Add a step that starts at the same moment as the previous step
+    pub fn with(self, duration: Duration, action: impl Fn() + 'static) -> Timeline {
+        self.state.update_value(|state| {
+            let offset = state.previous_offset;
+            state.add_step(offset, duration, Rc::new(action))
+        });
+        self
+    }
+
+    /// Add a step that sets `target` on the `source` signal of an animated signal after all previous
+    /// steps have ended. The step lasts as long as the animation towards the target, including its
+    /// delay and repetitions.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, create_animation, easing, timeline::Timeline, AnimationContext, AnimationTarget};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (target, set_target) = create_signal(AnimationTarget::new(0.0));
+    /// let value = create_animation(move || target.get());
+    ///
+    /// let timeline = Timeline::new()
+    ///     .then_target(set_target, (1.0, Duration::from_millis(500), easing::LINEAR))
+    ///     .then_target(set_target, (0.0, Duration::from_millis(500), easing::LINEAR));
+    /// assert_eq!(timeline.duration(), Duration::from_secs(1));
+    ///
+    /// timeline.play();
+    /// clock.advance(Duration::from_millis(250));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 0.5);
+    ///
+    /// // The second step starts when the first animation ends
+    /// clock.advance(Duration::from_millis(250));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 1.0);
+    ///
+    /// clock.advance(Duration::from_millis(250));
+    /// animation_frame();
+    /// assert_eq!(value.get_untracked(), 0.5);
+    /// # runtime.dispose();
+    /// ```
+    pub fn then_target<T: Clone + 'static>(
+        self,
+        source: WriteSignal<AnimationTarget<T>>,
+        target: impl Into<AnimationTarget<T>>,
+    ) -> Timeline {
+        let target = target.into();
+        self.then(target.play_duration(), move || source.set(target.clone()))
+    }
+
+    /// Add a step that sets `target` on the `source` signal of an animated signal at the same moment
+    /// as the previous step, see [`then_target()`](Timeline::then_target)
+    pub fn with_target<T: Clone + 'static>(
+        self,
+        source: WriteSignal<AnimationTarget<T>>,
+        target: impl Into<AnimationTarget<T>>,
+    ) -> Timeline {
+        let target = target.into();
+        self.with(target.play_duration(), move || source.set(target.clone()))
+    }
+
+    /// Wait for the given duration after all previous steps have ended. Steps added with
+    /// [`with()`](Timeline::with) right after the delay start when the delay is over.
+    /// ```
+    /// # use std::time::Duration;
+    /// # use leptos::*;
+    /// # use leptos_animation::{clock::ManualClock, timeline::Timeline, AnimationContext};
+    /// # let runtime = create_runtime();
+    /// # let (context, animation_frame) = AnimationContext::provide_with_custom_request_animation_frame(|| {});
+    /// # let clock = ManualClock::new();
+    /// # context.set_clock(clock.clone());
+    /// let (shown, set_shown) = create_signal(false);
+    /// let timeline = Timeline::new()
+    ///     .delay(Duration::from_millis(500))
+    ///     .with(Duration::from_millis(500), move || set_shown.set(true));
+    /// assert_eq!(timeline.duration(), Duration::from_secs(1));
+    ///
+    /// timeline.play();
+    /// assert!(!shown.get());
+    ///
+    /// clock.advance(Duration::from_millis(500));
+    /// animation_frame();
+    /// assert!(shown.get());
+    /// # runtime.dispose();
+    /// ```
+    pub fn delay(self, duration: Duration) -> Timeline {
+        self.state.update_value(|state| {
+            state.end += duration;
+            state.previous_offset = state.end;
+        });
+        self
+    }
+
+    /// Add steps that start one after the other, `interval` apart, after all previous steps have
+    /// ended. Each of the steps takes `duration`.
+    pub fn stagger<F>(
+        self,
+        interval: Duration,
+        duration: Duration,
+        actions: impl IntoIterator<Item = F>,
+    ) -> Timeline
+    where
+        F: Fn() + 'static,
+    {
+        self.state.update_value(|state| {
+            let start = state.end;
+            for (i, action) in actions.into_iter().enumerate() {
+                state.add_step(start + interval * i as u32, duration, Rc::new(action));
+            }
+            state.previous_offset = start;
+        });
+        self
+    }
+
+    /// The total duration of the timeline from the start of the first step to the end of the last step
+    pub fn duration(&self) -> Duration {
+        self.state.with_value(|state| state.end)
+    }
+
+    /// Play the timeline from the start. Steps at the very start of the timeline run immediately.
+    /// Playing a timeline that is already playing restarts it.
+    pub fn play(&self) {
+        let now = self.context.now();
+        self.state.update_value(|state| {
+            state.start = Some(now);
+            state.next_step = 0;
+        });
+        Timeline::run_due_actions(self.context, self.state);
+    }
+
+    /// Stop playing the timeline. Steps that have already run are not undone.
+    pub fn stop(&self) {
+        self.state.update_value(|state| state.start = None);
+    }
+
+    /// Whether the timeline is currently playing
+    pub fn is_playing(&self) -> bool {
+        self.state.with_value(|state| state.start.is_some())
+    }
+
+    fn run_due_actions(context: AnimationContext, state: StoredValue<TimelineState>) {
+        let actions = state
+            .try_update_value(|state| state.due_actions(context.now()))
+            .unwrap_or_default();
+
+        // Run the actions outside of the update so they are free to use the timeline themselves
+        for action in actions {
+            action();
+        }
+
+        if state.with_value(|state| state.start.is_some()) {
+            context.request_animation_frame();
+        }
+    }
+}
+
+impl SignalDispose for Timeline {
+    fn dispose(self) {
+        self.state.dispose();
+        self.update_effect.dispose();
+    }
+}